@@ -1,5 +1,6 @@
-pub const DELAY_THRESHOLD_MIN: u16 = 120;
-pub const ORACLE_MAX_STALENESS_SLOTS: u64 = 150; // approx 60-90s depending on cluster
+// 지연 임계치/오라클 허용 지연 슬롯은 ProtocolConfig에서 관리한다.
+pub const MAX_ALLOWED_MINTS: usize = 8;
+pub const MAX_ALLOWED_QUEUES: usize = 4;
 pub const MAX_PARTICIPANTS: usize = 16;
 pub const MAX_POLICYHOLDERS: usize = 128;
 
@@ -7,7 +8,20 @@ pub const MAX_ROUTE_LEN: usize = 16;
 pub const MAX_FLIGHT_NO_LEN: usize = 16;
 pub const MAX_EXTERNAL_REF_LEN: usize = 32;
 pub const MAX_MASTER_PARTICIPANTS: usize = 8;
+pub const MAX_RESOLVERS: usize = 8;
+pub const MAX_ROLE_GRANTS: usize = 16;
+pub const MAX_PAYOUT_TIERS: usize = 8;
+// 트랜잭션 크기/연산 한도를 고려한 배치 발급 최대 건수.
+pub const MAX_FLIGHT_POLICY_BATCH: usize = 10;
+pub const MAX_SETTLE_BATCH: usize = 10;
 pub const MAX_SUBSCRIBER_REF_LEN: usize = 64;
+// coverage_end_ts 이후 정산을 마무리할 수 있도록 마스터 종료 전 두는 유예 기간(7일).
+pub const MASTER_SETTLEMENT_GRACE_SECS: i64 = 7 * 24 * 60 * 60;
+// 결과 미확정 child 정책 만료 대기 시간 상한(30일).
+pub const MAX_RESOLUTION_TIMEOUT_HOURS: u16 = 30 * 24;
+// 결과 확정 후 이의 제기 기간 상한(7일).
+pub const MAX_CHALLENGE_PERIOD_SECS: i64 = 7 * 24 * 60 * 60;
+pub const MAX_MIN_LEAD_TIME_SECS: i64 = 7 * 24 * 60 * 60;
 
 pub const POLICY_SPACE: usize = 260;
 pub const UNDERWRITING_SPACE: usize = 1292;
@@ -20,3 +34,7 @@ pub const REGISTRY_SPACE: usize = 8192;
 // Generous buffer to simplify migration; can be tightened after schema finalization.
 pub const MASTER_POLICY_SPACE: usize = 4096;
 pub const FLIGHT_POLICY_SPACE: usize = 1024;
+pub const FLIGHT_RESOLUTION_SPACE: usize = 512;
+pub const PROTOCOL_CONFIG_SPACE: usize = 1024;
+pub const MASTER_AMENDMENT_SPACE: usize = 1024;
+pub const XOL_EVENT_SPACE: usize = 128;
@@ -46,4 +46,36 @@ pub enum OpenParamError {
     InvalidSettlementTarget,
     #[msg("Invalid account list")]
     InvalidAccountList,
+    #[msg("Master policy is closed")]
+    MasterClosed,
+    #[msg("Master policy has unsettled flight policies")]
+    UnsettledFlightPolicies,
+    #[msg("Master policy is cancelled")]
+    MasterCancelled,
+    #[msg("Master policy requires oracle-verified resolution")]
+    OracleResolutionRequired,
+    #[msg("Master policy requires resolver quorum")]
+    ResolverQuorumRequired,
+    #[msg("Challenge period is still active")]
+    ChallengePeriodActive,
+    #[msg("Challenge period has ended")]
+    ChallengePeriodEnded,
+    #[msg("Departure is outside the coverage window")]
+    DepartureOutsideCoverage,
+    #[msg("Departure is within the purchase cutoff")]
+    PurchaseCutoffPassed,
+    #[msg("Aggregate liability limit exceeded")]
+    ExposureLimitExceeded,
+    #[msg("Maximum policy count exceeded")]
+    PolicyCountLimitExceeded,
+    #[msg("Pool wallet balance does not cover reserved liability")]
+    InsufficientPoolCapacity,
+    #[msg("Withdrawal exceeds pool excess")]
+    WithdrawalExceedsExcess,
+    #[msg("Protocol is paused")]
+    ProtocolPaused,
+    #[msg("Currency mint is not allowed")]
+    MintNotAllowed,
+    #[msg("Oracle queue is not allowed")]
+    OracleQueueNotAllowed,
 }
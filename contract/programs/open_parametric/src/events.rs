@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+// 참여사/재보험사가 구독하는 마스터 권한 인계 이벤트. role은 MasterAuthority 값.
+#[event]
+pub struct MasterAuthorityProposed {
+    pub master: Pubkey,
+    pub role: u8,
+    pub current: Pubkey,
+    pub proposed: Pubkey,
+}
+
+#[event]
+pub struct MasterAuthorityTransferred {
+    pub master: Pubkey,
+    pub role: u8,
+    pub previous: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+// 갱신 마스터와 이전 마스터를 잇는 보고용 이벤트.
+#[event]
+pub struct MasterPolicyRenewed {
+    pub predecessor: Pubkey,
+    pub master: Pubkey,
+    pub master_id: u64,
+    pub leader: Pubkey,
+}
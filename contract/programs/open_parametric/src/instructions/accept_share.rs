@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::OpenParamError;
 use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
 
 #[derive(Accounts)]
 pub struct AcceptShare<'info> {
@@ -15,10 +16,11 @@ pub struct AcceptShare<'info> {
     #[account(mut, seeds = [b"pool", policy.key().as_ref()], bump = risk_pool.bump)]
     pub risk_pool: Account<'info, RiskPool>,
     #[account(mut)]
-    pub participant_token: Account<'info, TokenAccount>,
+    pub participant_token: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
-    pub vault: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(ctx: Context<AcceptShare>, index: u8, deposit_amount: u64) -> Result<()> {
@@ -50,6 +52,10 @@ pub fn handler(ctx: Context<AcceptShare>, index: u8, deposit_amount: u64) -> Res
         ctx.accounts.vault.mint == policy.currency_mint,
         OpenParamError::InvalidInput
     );
+    require!(
+        ctx.accounts.currency_mint.key() == policy.currency_mint,
+        OpenParamError::InvalidInput
+    );
     require!(
         ctx.accounts.participant_token.owner == ctx.accounts.participant.key(),
         OpenParamError::Unauthorized
@@ -70,23 +76,23 @@ pub fn handler(ctx: Context<AcceptShare>, index: u8, deposit_amount: u64) -> Res
     require!(share.ratio_bps > 0, OpenParamError::InvalidRatio);
     require!(deposit_amount > 0, OpenParamError::InvalidAmount);
 
+    // 참여자 지갑에서 풀 금고(vault)로 담보금을 이체한다.
+    // transfer fee가 있는 mint는 vault에 실제 입금된 금액으로 최소 예치금을 검증하고 기록한다.
+    let deposit_amount = transfer_checked_received(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.participant_token.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.vault.to_account_info(),
+        ctx.accounts.participant.to_account_info(),
+        &[],
+        deposit_amount,
+    )?;
     let required = calc_required_deposit(policy.payout_amount, share.ratio_bps)?;
     require!(
         deposit_amount >= required,
         OpenParamError::InsufficientEscrow
     );
 
-    // 참여자 지갑에서 풀 금고(vault)로 담보금을 이체한다.
-    let cpi_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.participant_token.to_account_info(),
-            to: ctx.accounts.vault.to_account_info(),
-            authority: ctx.accounts.participant.to_account_info(),
-        },
-    );
-    token::transfer(cpi_ctx, deposit_amount)?;
-
     share.status = ParticipantStatus::Accepted as u8;
     share.escrow = ctx.accounts.participant_token.key();
     share.escrowed_amount = deposit_amount;
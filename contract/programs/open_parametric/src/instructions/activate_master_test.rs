@@ -19,6 +19,8 @@ fn participant(confirmed: bool, has_wallets: bool) -> MasterParticipant {
         } else {
             Pubkey::default()
         },
+        capital_deposited: 0,
+        capital_withdrawn: 0,
     }
 }
 
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::OpenParamError;
 use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
 
 // ─── Approve ──────────────────────────────────────────────────────────────────
 
@@ -48,10 +49,11 @@ pub struct SettleClaim<'info> {
     #[account(mut, seeds = [b"pool", policy.key().as_ref()], bump = risk_pool.bump)]
     pub risk_pool: Account<'info, RiskPool>,
     #[account(mut)]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
-    pub beneficiary_token: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub beneficiary_token: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn settle_handler(ctx: Context<SettleClaim>) -> Result<()> {
@@ -79,6 +81,10 @@ pub fn settle_handler(ctx: Context<SettleClaim>) -> Result<()> {
         ctx.accounts.beneficiary_token.mint == policy.currency_mint,
         OpenParamError::InvalidInput
     );
+    require!(
+        ctx.accounts.currency_mint.key() == policy.currency_mint,
+        OpenParamError::InvalidInput
+    );
 
     let policy_key = policy.key();
     let seeds = &[
@@ -88,16 +94,15 @@ pub fn settle_handler(ctx: Context<SettleClaim>) -> Result<()> {
     ];
     let signer = &[&seeds[..]];
     // RiskPool PDA 서명으로 vault -> 수익자 토큰계정으로 이체한다.
-    let cpi_ctx = CpiContext::new_with_signer(
+    transfer_checked_received(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.vault.to_account_info(),
-            to: ctx.accounts.beneficiary_token.to_account_info(),
-            authority: ctx.accounts.risk_pool.to_account_info(),
-        },
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.beneficiary_token.to_account_info(),
+        ctx.accounts.risk_pool.to_account_info(),
         signer,
-    );
-    token::transfer(cpi_ctx, claim.payout_amount)?;
+        claim.payout_amount,
+    )?;
 
     ctx.accounts.risk_pool.available_balance = ctx
         .accounts
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CancelMasterPolicy<'info> {
+    pub leader: Signer<'info>,
+    pub reinsurer: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+}
+
+pub fn handler(ctx: Context<CancelMasterPolicy>) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+
+    // 마스터 취소는 리더가 재보험사 동의(공동 서명)를 받아야만 가능하다.
+    require!(
+        master.status == MasterPolicyStatus::PendingConfirm as u8
+            || master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::InvalidState
+    );
+    require!(
+        ctx.accounts.leader.key() == master.leader,
+        OpenParamError::Unauthorized
+    );
+    require!(
+        ctx.accounts.reinsurer.key() == master.reinsurer,
+        OpenParamError::Unauthorized
+    );
+
+    // 취소 이후 신규 발급은 차단되고, 미정산 child 정책은 void_flight_policy로 환급한다.
+    master.status = MasterPolicyStatus::Cancelled as u8;
+    master.closed_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
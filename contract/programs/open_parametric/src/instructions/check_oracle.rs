@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use switchboard_on_demand::{default_queue, Instructions, QuoteVerifier, SlotHashes};
+use switchboard_on_demand::{Instructions, QuoteVerifier, SlotHashes};
 
 use crate::constants::*;
 use crate::errors::OpenParamError;
@@ -22,8 +22,9 @@ pub struct CheckOracle<'info> {
     pub payer: Signer<'info>,
     /// CHECK: oracle feed address is validated against policy.oracle_feed
     pub oracle_feed: UncheckedAccount<'info>,
-    /// CHECK: Switchboard oracle queue; key validated against default_queue()
-    #[account(address = default_queue())]
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: Switchboard oracle queue; key validated against protocol_config.allowed_queues
     pub queue: UncheckedAccount<'info>,
     /// CHECK: slot hashes sysvar; validated by SlotHashes sysvar trait
     pub slot_hashes: Sysvar<'info, SlotHashes>,
@@ -44,12 +45,18 @@ pub fn handler(ctx: Context<CheckOracle>, oracle_round: u64) -> Result<()> {
         OpenParamError::InvalidInput
     );
 
+    let config = &ctx.accounts.protocol_config;
+    require!(
+        config.allowed_queues.contains(&ctx.accounts.queue.key()),
+        OpenParamError::OracleQueueNotAllowed
+    );
+
     let oracle_quote = QuoteVerifier::new()
         .queue(ctx.accounts.queue.to_account_info())
         .slothash_sysvar(ctx.accounts.slot_hashes.to_account_info())
         .ix_sysvar(ctx.accounts.instructions.to_account_info())
         .clock_slot(Clock::get()?.slot)
-        .max_age(ORACLE_MAX_STALENESS_SLOTS)
+        .max_age(config.oracle_max_staleness_slots)
         .verify_instruction_at(0)
         .map_err(|_| OpenParamError::OracleStale)?;
 
@@ -57,7 +64,7 @@ pub fn handler(ctx: Context<CheckOracle>, oracle_round: u64) -> Result<()> {
     let current_slot = Clock::get()?.slot;
     let staleness = current_slot.saturating_sub(oracle_quote.slot());
     require!(
-        staleness <= ORACLE_MAX_STALENESS_SLOTS,
+        staleness <= config.oracle_max_staleness_slots,
         OpenParamError::OracleStale
     );
 
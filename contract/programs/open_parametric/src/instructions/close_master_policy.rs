@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseMasterPolicy<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+}
+
+pub fn handler(ctx: Context<CloseMasterPolicy>) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+
+    // 마스터 종료는 Active 상태에서 권한자(leader/operator)만 수행할 수 있다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        ctx.accounts.authority.key() == master.leader
+            || ctx.accounts.authority.key() == master.operator,
+        OpenParamError::Unauthorized
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    validate_master_close(master.coverage_end_ts, master.open_policy_count, now)?;
+
+    // 누적 집계는 이 시점 값이 최종값으로 고정된다.
+    master.status = MasterPolicyStatus::Closed as u8;
+    master.closed_at = now;
+
+    Ok(())
+}
+
+pub(crate) fn validate_master_close(
+    coverage_end_ts: i64,
+    open_policy_count: u64,
+    now: i64,
+) -> std::result::Result<(), OpenParamError> {
+    // 보장 종료 + 정산 유예 기간이 지나야 하고, 미정산 child 정책이 없어야 한다.
+    let closable_at = coverage_end_ts
+        .checked_add(MASTER_SETTLEMENT_GRACE_SECS)
+        .ok_or(OpenParamError::MathOverflow)?;
+    if now < closable_at {
+        return Err(OpenParamError::InvalidTimeWindow);
+    }
+    if open_policy_count > 0 {
+        return Err(OpenParamError::UnsettledFlightPolicies);
+    }
+    Ok(())
+}
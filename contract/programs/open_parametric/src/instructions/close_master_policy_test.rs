@@ -0,0 +1,25 @@
+use crate::constants::MASTER_SETTLEMENT_GRACE_SECS;
+use crate::errors::OpenParamError;
+
+use super::close_master_policy::validate_master_close;
+
+#[test]
+fn close_is_allowed_after_grace_period_with_no_open_policies() {
+    // 보장 종료 + 유예 기간 경과, 미정산 건수 0이면 종료 가능.
+    let end = 1_700_000_000;
+    assert!(validate_master_close(end, 0, end + MASTER_SETTLEMENT_GRACE_SECS).is_ok());
+}
+
+#[test]
+fn close_rejects_early_call_or_unsettled_policies() {
+    // 유예 기간 이전 호출 또는 미정산 child가 남아 있으면 실패해야 한다.
+    let end = 1_700_000_000;
+    assert!(matches!(
+        validate_master_close(end, 0, end + MASTER_SETTLEMENT_GRACE_SECS - 1),
+        Err(OpenParamError::InvalidTimeWindow)
+    ));
+    assert!(matches!(
+        validate_master_close(end, 2, end + MASTER_SETTLEMENT_GRACE_SECS),
+        Err(OpenParamError::UnsettledFlightPolicies)
+    ));
+}
@@ -0,0 +1,327 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::create_flight_policy_from_master::{
+    build_flight_policy, check_beneficiary_token, issuance_deductions, pay_treasury,
+    premium_paid_with_deductions, record_issuance, record_premium_deductions,
+    split_received_premium, validate_flight_params, verify_pool_capacity,
+};
+use super::manage_master_roles::has_master_role;
+
+#[derive(Accounts)]
+pub struct CreateFlightPoliciesBatch<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub payer_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    pub reinsurer_pool_token: InterfaceAccount<'info, TokenAccount>,
+    /// XoL 레이어 사용 시 필요
+    pub xol_pool_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 프로토콜 수수료 차감 시 필요
+    #[account(mut)]
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금 차감 시 필요
+    #[account(mut)]
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts 구성: [FlightPolicy PDA × params.len()] + [참여사 pool_wallet × N]
+///   + [beneficiary_token을 지정한 params 순서대로의 수취 토큰 계정 × M]
+pub fn handler<'a>(
+    ctx: Context<'_, '_, 'a, 'a, CreateFlightPoliciesBatch<'a>>,
+    params: Vec<CreateFlightPolicyParams>,
+) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    require!(
+        !ctx.accounts.protocol_config.paused,
+        OpenParamError::ProtocolPaused
+    );
+    // 단건 발급과 동일한 마스터 상태/권한 검증 후 배치 크기와 계정 목록을 확인한다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        has_master_role(master, ctx.accounts.creator.key(), MasterRole::Issuer),
+        OpenParamError::Unauthorized
+    );
+    validate_batch_accounts(
+        &params,
+        ctx.remaining_accounts.len(),
+        master.participants.len(),
+    )?;
+    let (flight_infos, rest) = ctx.remaining_accounts.split_at(params.len());
+    let (pool_infos, beneficiary_infos) = rest.split_at(master.participants.len());
+    // 하나라도 검증에 실패하면 트랜잭션 전체가 롤백된다.
+    let now = Clock::get()?.unix_timestamp;
+    for p in &params {
+        validate_flight_params(master, p, now)?;
+    }
+    let mut beneficiary_infos = beneficiary_infos.iter();
+    for p in params
+        .iter()
+        .filter(|p| p.beneficiary_token != Pubkey::default())
+    {
+        let info = beneficiary_infos
+            .next()
+            .ok_or(OpenParamError::InvalidAccountList)?;
+        let beneficiary: InterfaceAccount<TokenAccount> = InterfaceAccount::try_from(info)?;
+        check_beneficiary_token(
+            p.beneficiary_token,
+            Some((beneficiary.key(), beneficiary.mint)),
+            master.currency_mint,
+        )?;
+    }
+
+    require!(
+        ctx.accounts.leader_deposit_token.key() == master.leader_deposit_wallet,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.payer_token.owner == ctx.accounts.creator.key(),
+        OpenParamError::Unauthorized
+    );
+    require!(
+        ctx.accounts.payer_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.leader_deposit_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+
+    let count = params.len() as u64;
+    let premium_total = master
+        .premium_per_policy
+        .checked_mul(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let (protocol_fee, premium_tax) = issuance_deductions(master)?;
+    let fee_total = protocol_fee
+        .checked_mul(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let tax_total = premium_tax
+        .checked_mul(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let net_premium_total = premium_total
+        .checked_sub(fee_total + tax_total)
+        .ok_or(OpenParamError::MathOverflow)?;
+
+    // 배치 전체 보험료(수수료/세금 차감 후)를 한 번의 이체로 leader_deposit 지갑에 선납한다.
+    // transfer fee로 덜 입금된 금액은 배치 내 정책에 나눠 각 정책의 납입 보험료에 반영한다.
+    let received_total = transfer_checked_received(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.payer_token.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.leader_deposit_token.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        &[],
+        net_premium_total,
+    )?;
+    let received_premiums = split_received_premium(received_total, count)?;
+    pay_treasury(
+        ctx.accounts.fee_treasury_token.as_ref(),
+        master.fee_treasury_token,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.payer_token.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        &[],
+        fee_total,
+    )?;
+    pay_treasury(
+        ctx.accounts.tax_treasury_token.as_ref(),
+        master.tax_treasury_token,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.payer_token.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        &[],
+        tax_total,
+    )?;
+
+    let master_key = master.key();
+    for ((p, flight_info), received_premium) in params
+        .into_iter()
+        .zip(flight_infos.iter())
+        .zip(received_premiums)
+    {
+        // remaining_accounts는 params 순서대로의 FlightPolicy PDA여야 한다.
+        let child_id_bytes = p.child_policy_id.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(
+            &[b"flight_policy", master_key.as_ref(), &child_id_bytes],
+            ctx.program_id,
+        );
+        require!(
+            flight_info.key() == expected,
+            OpenParamError::InvalidAccountList
+        );
+        require!(flight_info.data_is_empty(), OpenParamError::AlreadyExists);
+
+        let seeds = &[
+            b"flight_policy".as_ref(),
+            master_key.as_ref(),
+            child_id_bytes.as_ref(),
+            &[bump],
+        ];
+        create_pda_account(
+            &ctx.accounts.system_program,
+            ctx.accounts.creator.to_account_info(),
+            flight_info.to_account_info(),
+            &[&seeds[..]],
+            FLIGHT_POLICY_SPACE,
+            ctx.program_id,
+        )?;
+
+        let mut flight = build_flight_policy(
+            &ctx.accounts.master_policy,
+            ctx.accounts.creator.key(),
+            ctx.accounts.payer_token.key(),
+            p,
+            bump,
+            now,
+        );
+        flight.premium_paid =
+            premium_paid_with_deductions(received_premium, protocol_fee, premium_tax)?;
+        flight.protocol_fee_paid = protocol_fee;
+        flight.premium_tax_paid = premium_tax;
+        let mut data = flight_info.try_borrow_mut_data()?;
+        let mut writer: &mut [u8] = &mut data;
+        flight.try_serialize(&mut writer)?;
+    }
+
+    // 마스터 누적 집계를 배치 단위로 갱신한 뒤 풀 지갑 잔액이 예약 부담액을 감당하는지 확인한다.
+    let premium_paid_total = premium_paid_with_deductions(received_total, fee_total, tax_total)?;
+    record_issuance(&mut ctx.accounts.master_policy, count, premium_paid_total)?;
+    record_premium_deductions(&mut ctx.accounts.master_policy, fee_total, tax_total)?;
+    verify_pool_capacity(
+        &ctx.accounts.master_policy,
+        &ctx.accounts.reinsurer_pool_token,
+        ctx.accounts.xol_pool_token.as_ref(),
+        pool_infos,
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn create_pda_account<'info>(
+    system_program: &Program<'info, System>,
+    payer: AccountInfo<'info>,
+    target: AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+    space: usize,
+    owner: &Pubkey,
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space);
+    let system_program = system_program.to_account_info();
+    // lamports가 없으면 create_account, 누군가 미리 lamports를 보내 둔 PDA는 Anchor init과 같이
+    // 부족한 rent만 채운 뒤 allocate/assign으로 생성해 발급이 막히지 않게 한다.
+    let current_lamports = target.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                CreateAccount {
+                    from: payer,
+                    to: target,
+                },
+                signer,
+            ),
+            rent_lamports,
+            space as u64,
+            owner,
+        );
+    }
+    let top_up = rent_top_up(current_lamports, rent_lamports);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer,
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            Assign {
+                account_to_assign: target,
+            },
+            signer,
+        ),
+        owner,
+    )
+}
+
+pub(crate) fn rent_top_up(current_lamports: u64, rent_lamports: u64) -> u64 {
+    // 이미 들어 있는 lamports를 제외하고 rent-exempt 최소 잔액까지 부족분만 채운다.
+    rent_lamports.saturating_sub(current_lamports)
+}
+
+pub(crate) fn validate_batch_accounts(
+    params: &[CreateFlightPolicyParams],
+    remaining_len: usize,
+    participant_count: usize,
+) -> std::result::Result<(), OpenParamError> {
+    // 배치 크기 제한, 계정 목록 길이(FlightPolicy × params + pool_wallet × 참여사 + 수취 계정 × 지정 건수),
+    // 배치 내 중복 id를 확인한다.
+    if params.is_empty() || params.len() > MAX_FLIGHT_POLICY_BATCH {
+        return Err(OpenParamError::InvalidInput);
+    }
+    let beneficiary_count = params
+        .iter()
+        .filter(|p| p.beneficiary_token != Pubkey::default())
+        .count();
+    if remaining_len != params.len() + participant_count + beneficiary_count {
+        return Err(OpenParamError::InvalidAccountList);
+    }
+    for (i, p) in params.iter().enumerate() {
+        if params[..i]
+            .iter()
+            .any(|q| q.child_policy_id == p.child_policy_id)
+        {
+            return Err(OpenParamError::AlreadyExists);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::MAX_FLIGHT_POLICY_BATCH;
+use crate::errors::OpenParamError;
+use crate::state::CreateFlightPolicyParams;
+
+use super::create_flight_policies_batch::{rent_top_up, validate_batch_accounts};
+
+fn params(child_policy_id: u64) -> CreateFlightPolicyParams {
+    CreateFlightPolicyParams {
+        child_policy_id,
+        subscriber_ref: "SUB".to_string(),
+        flight_no: "KE001".to_string(),
+        route: "ICN-NRT".to_string(),
+        departure_ts: 1_700_000_000,
+        delay_feed_id: [0u8; 32],
+        cancel_feed_id: [0u8; 32],
+        beneficiary_token: Default::default(),
+    }
+}
+
+#[test]
+fn batch_accounts_are_flights_then_participant_pools() {
+    // params 수만큼의 FlightPolicy 계정 뒤에 참여사 pool_wallet이 와야 한다.
+    let batch = vec![params(1), params(2), params(3)];
+    assert!(validate_batch_accounts(&batch, 3 + 2, 2).is_ok());
+    for remaining_len in [3, 4, 6] {
+        assert!(matches!(
+            validate_batch_accounts(&batch, remaining_len, 2),
+            Err(OpenParamError::InvalidAccountList)
+        ));
+    }
+}
+
+#[test]
+fn batch_size_is_bounded() {
+    // 빈 배치와 최대 건수를 넘는 배치는 거부한다.
+    assert!(matches!(
+        validate_batch_accounts(&[], 2, 2),
+        Err(OpenParamError::InvalidInput)
+    ));
+    let max: Vec<_> = (0..MAX_FLIGHT_POLICY_BATCH as u64).map(params).collect();
+    assert!(validate_batch_accounts(&max, max.len() + 1, 1).is_ok());
+    let over: Vec<_> = (0..=MAX_FLIGHT_POLICY_BATCH as u64).map(params).collect();
+    assert!(matches!(
+        validate_batch_accounts(&over, over.len() + 1, 1),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn duplicate_child_ids_in_one_batch_are_rejected() {
+    // 같은 child_policy_id가 배치 안에 두 번 있으면 전체 배치가 실패한다.
+    let batch = vec![params(7), params(8), params(7)];
+    assert!(matches!(
+        validate_batch_accounts(&batch, 3 + 1, 1),
+        Err(OpenParamError::AlreadyExists)
+    ));
+}
+
+#[test]
+fn prefunded_flight_pda_only_tops_up_rent_shortfall() {
+    // 미리 lamports가 들어 있는 PDA는 부족분만 채우고, 이미 충분하면 추가 이체가 없다.
+    assert_eq!(rent_top_up(0, 2_000_000), 2_000_000);
+    assert_eq!(rent_top_up(1, 2_000_000), 1_999_999);
+    assert_eq!(rent_top_up(5_000_000, 2_000_000), 0);
+}
+
+#[test]
+fn beneficiary_accounts_follow_pools_for_flagged_flights() {
+    // beneficiary_token을 지정한 건 수만큼 수취 토큰 계정이 pool_wallet 뒤에 추가되어야 한다.
+    let mut direct = params(2);
+    direct.beneficiary_token = Pubkey::new_unique();
+    let batch = vec![params(1), direct];
+    assert!(validate_batch_accounts(&batch, 2 + 2 + 1, 2).is_ok());
+    assert!(matches!(
+        validate_batch_accounts(&batch, 2 + 2, 2),
+        Err(OpenParamError::InvalidAccountList)
+    ));
+}
@@ -1,15 +1,22 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::*;
 use crate::errors::OpenParamError;
+use crate::math::{max_policy_liability, split_by_bps, BPS_DENOM};
 use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::manage_master_roles::has_master_role;
+use super::settle_flight_claim::claim_cession_bps;
 
 #[derive(Accounts)]
 #[instruction(params: CreateFlightPolicyParams)]
 pub struct CreateFlightPolicyFromMaster<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     #[account(mut)]
     pub master_policy: Account<'info, MasterPolicy>,
     #[account(
@@ -21,40 +28,54 @@ pub struct CreateFlightPolicyFromMaster<'info> {
     )]
     pub flight_policy: Account<'info, FlightPolicy>,
     #[account(mut)]
-    pub payer_token: Account<'info, TokenAccount>,
+    pub payer_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    pub reinsurer_pool_token: InterfaceAccount<'info, TokenAccount>,
+    /// XoL 레이어 사용 시 필요
+    pub xol_pool_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 프로토콜 수수료 차감 시 필요
     #[account(mut)]
-    pub leader_deposit_token: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금 차감 시 필요
+    #[account(mut)]
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// params.beneficiary_token 지정 시 필요
+    pub beneficiary_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-pub fn handler(
-    ctx: Context<CreateFlightPolicyFromMaster>,
+/// remaining_accounts 구성: [참여사 pool_wallet × N]
+pub fn handler<'a>(
+    ctx: Context<'_, '_, 'a, 'a, CreateFlightPolicyFromMaster<'a>>,
     params: CreateFlightPolicyParams,
 ) -> Result<()> {
     let master = &ctx.accounts.master_policy;
-    // 마스터 활성 상태/호출 권한/입력 길이를 먼저 검증한다.
     require!(
-        master.status == MasterPolicyStatus::Active as u8,
-        OpenParamError::MasterNotActive
+        !ctx.accounts.protocol_config.paused,
+        OpenParamError::ProtocolPaused
     );
+    // 마스터 활성 상태/호출 권한/입력 길이를 먼저 검증한다.
     require!(
-        ctx.accounts.creator.key() == master.leader
-            || ctx.accounts.creator.key() == master.operator,
-        OpenParamError::Unauthorized
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
     );
     require!(
-        params.subscriber_ref.len() <= MAX_SUBSCRIBER_REF_LEN,
-        OpenParamError::InputTooLong
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
     );
     require!(
-        params.flight_no.len() <= MAX_FLIGHT_NO_LEN,
-        OpenParamError::InputTooLong
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
     );
     require!(
-        params.route.len() <= MAX_ROUTE_LEN,
-        OpenParamError::InputTooLong
+        has_master_role(master, ctx.accounts.creator.key(), MasterRole::Issuer),
+        OpenParamError::Unauthorized
     );
+    let now = Clock::get()?.unix_timestamp;
+    validate_flight_params(master, &params, now)?;
 
     require!(
         ctx.accounts.leader_deposit_token.key() == master.leader_deposit_wallet,
@@ -72,37 +93,555 @@ pub fn handler(
         ctx.accounts.leader_deposit_token.mint == master.currency_mint,
         OpenParamError::InvalidInput
     );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    check_beneficiary_token(
+        params.beneficiary_token,
+        ctx.accounts
+            .beneficiary_token
+            .as_ref()
+            .map(|t| (t.key(), t.mint)),
+        master.currency_mint,
+    )?;
 
     // 가입 프리미엄은 생성자 지갑에서 leader_deposit 지갑으로 선납된다.
-    let transfer_ctx = CpiContext::new(
+    // 발급 시점 차감 설정이면 수수료/세금은 treasury로 바로 보내고 나머지만 선납한다.
+    let (protocol_fee, premium_tax) = issuance_deductions(master)?;
+    let net_premium = master
+        .premium_per_policy
+        .checked_sub(protocol_fee + premium_tax)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let received_premium = transfer_checked_received(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.payer_token.to_account_info(),
-            to: ctx.accounts.leader_deposit_token.to_account_info(),
-            authority: ctx.accounts.creator.to_account_info(),
-        },
-    );
-    token::transfer(transfer_ctx, master.premium_per_policy)?;
+        ctx.accounts.payer_token.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.leader_deposit_token.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        &[],
+        net_premium,
+    )?;
+    pay_treasury(
+        ctx.accounts.fee_treasury_token.as_ref(),
+        master.fee_treasury_token,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.payer_token.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        &[],
+        protocol_fee,
+    )?;
+    pay_treasury(
+        ctx.accounts.tax_treasury_token.as_ref(),
+        master.tax_treasury_token,
+        ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.payer_token.to_account_info(),
+        ctx.accounts.creator.to_account_info(),
+        &[],
+        premium_tax,
+    )?;
 
     // Child(Flight) 정책 스냅샷을 생성 시점 값으로 초기화한다.
-    let now = Clock::get()?.unix_timestamp;
-    let flight = &mut ctx.accounts.flight_policy;
-    flight.child_policy_id = params.child_policy_id;
-    flight.master = master.key();
-    flight.creator = ctx.accounts.creator.key();
-    flight.subscriber_ref = params.subscriber_ref;
-    flight.flight_no = params.flight_no;
-    flight.route = params.route;
-    flight.departure_ts = params.departure_ts;
-    flight.premium_paid = master.premium_per_policy;
-    flight.delay_minutes = 0;
-    flight.cancelled = false;
-    flight.payout_amount = 0;
-    flight.status = FlightPolicyStatus::AwaitingOracle as u8;
-    flight.premium_distributed = false;
-    flight.created_at = now;
-    flight.updated_at = now;
-    flight.bump = ctx.bumps.flight_policy;
+    let mut flight = build_flight_policy(
+        master,
+        ctx.accounts.creator.key(),
+        ctx.accounts.payer_token.key(),
+        params,
+        ctx.bumps.flight_policy,
+        now,
+    );
+    flight.premium_paid =
+        premium_paid_with_deductions(received_premium, protocol_fee, premium_tax)?;
+    flight.protocol_fee_paid = protocol_fee;
+    flight.premium_tax_paid = premium_tax;
+    let premium = flight.premium_paid;
+    ctx.accounts.flight_policy.set_inner(flight);
+
+    // 마스터 누적 집계(발급 건수/미정산 건수/수취 보험료)를 갱신한다.
+    record_issuance(&mut ctx.accounts.master_policy, 1, premium)?;
+    record_premium_deductions(&mut ctx.accounts.master_policy, protocol_fee, premium_tax)?;
+    verify_pool_capacity(
+        &ctx.accounts.master_policy,
+        &ctx.accounts.reinsurer_pool_token,
+        ctx.accounts.xol_pool_token.as_ref(),
+        ctx.remaining_accounts,
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn validate_flight_params(
+    master: &MasterPolicy,
+    params: &CreateFlightPolicyParams,
+    now: i64,
+) -> Result<()> {
+    require!(
+        params.subscriber_ref.len() <= MAX_SUBSCRIBER_REF_LEN,
+        OpenParamError::InputTooLong
+    );
+    require!(
+        params.flight_no.len() <= MAX_FLIGHT_NO_LEN,
+        OpenParamError::InputTooLong
+    );
+    require!(
+        params.route.len() <= MAX_ROUTE_LEN,
+        OpenParamError::InputTooLong
+    );
+    // 오라클 전용 마스터는 결과 확정에 쓸 feed id가 반드시 지정되어야 한다.
+    if master.oracle_only_resolution {
+        require!(
+            params.delay_feed_id != [0u8; 32] && params.cancel_feed_id != [0u8; 32],
+            OpenParamError::InvalidInput
+        );
+    }
+    validate_departure(
+        master.coverage_start_ts,
+        master.coverage_end_ts,
+        master.min_lead_time_secs,
+        params.departure_ts,
+        now,
+    )?;
+    Ok(())
+}
+
+pub(crate) fn check_beneficiary_token(
+    expected: Pubkey,
+    provided: Option<(Pubkey, Pubkey)>,
+    currency_mint: Pubkey,
+) -> std::result::Result<(), OpenParamError> {
+    // 직접 지급 대상은 발급 시점에 실제 토큰 계정(key, mint)으로 확인해 정산 단계에서 지급이 막히지 않게 한다.
+    if expected == Pubkey::default() {
+        return Ok(());
+    }
+    let (key, mint) = provided.ok_or(OpenParamError::InvalidAccountList)?;
+    if key != expected || mint != currency_mint {
+        return Err(OpenParamError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_departure(
+    coverage_start_ts: i64,
+    coverage_end_ts: i64,
+    min_lead_time_secs: i64,
+    departure_ts: i64,
+    now: i64,
+) -> std::result::Result<(), OpenParamError> {
+    // 출발 시각은 마스터 보장 기간 안이어야 하고, 발급 시점부터 최소 리드타임 이상 남아 있어야 한다.
+    if departure_ts < coverage_start_ts || departure_ts > coverage_end_ts {
+        return Err(OpenParamError::DepartureOutsideCoverage);
+    }
+    if departure_ts < now.saturating_add(min_lead_time_secs) {
+        return Err(OpenParamError::PurchaseCutoffPassed);
+    }
+    Ok(())
+}
+
+pub(crate) fn build_flight_policy(
+    master: &Account<MasterPolicy>,
+    creator: Pubkey,
+    payer_token: Pubkey,
+    params: CreateFlightPolicyParams,
+    bump: u8,
+    now: i64,
+) -> FlightPolicy {
+    FlightPolicy {
+        child_policy_id: params.child_policy_id,
+        master: master.key(),
+        creator,
+        subscriber_ref: params.subscriber_ref,
+        flight_no: params.flight_no,
+        route: params.route,
+        departure_ts: params.departure_ts,
+        premium_paid: master.premium_per_policy,
+        delay_minutes: 0,
+        cancelled: false,
+        payout_amount: 0,
+        status: FlightPolicyStatus::AwaitingOracle as u8,
+        premium_distributed: false,
+        created_at: now,
+        updated_at: now,
+        bump,
+        payer_token,
+        delay_feed_id: params.delay_feed_id,
+        cancel_feed_id: params.cancel_feed_id,
+        challenge_deadline: 0,
+        disputed_by: Pubkey::default(),
+        disputed_at: 0,
+        pre_dispute_status: 0,
+        beneficiary_token: params.beneficiary_token,
+        max_liability: max_policy_liability(&master.payout_tiers, master.cancellation_payout),
+        protocol_fee_paid: 0,
+        premium_tax_paid: 0,
+        terms_version: master.terms_version,
+        payout_tiers: master.payout_tiers.clone(),
+        cancellation_payout: master.cancellation_payout,
+        ceded_ratio_bps: master.ceded_ratio_bps,
+        reins_commission_bps: master.reins_commission_bps,
+        reinsurer_effective_bps: master.reinsurer_effective_bps,
+    }
+}
+
+pub(crate) fn calc_premium_deductions(
+    premium: u64,
+    protocol_fee_bps: u16,
+    premium_tax_bps: u16,
+) -> std::result::Result<(u64, u64), OpenParamError> {
+    // 보험료에 각 비율을 곱해 (프로토콜 수수료, 보험료 세금)을 구한다(내림).
+    let protocol_fee = premium
+        .checked_mul(protocol_fee_bps as u64)
+        .ok_or(OpenParamError::MathOverflow)?
+        / BPS_DENOM;
+    let premium_tax = premium
+        .checked_mul(premium_tax_bps as u64)
+        .ok_or(OpenParamError::MathOverflow)?
+        / BPS_DENOM;
+    Ok((protocol_fee, premium_tax))
+}
+
+pub(crate) fn issuance_deductions(
+    master: &MasterPolicy,
+) -> std::result::Result<(u64, u64), OpenParamError> {
+    if master.deduction_timing != DeductionTiming::AtIssuance as u8 {
+        return Ok((0, 0));
+    }
+    calc_premium_deductions(
+        master.premium_per_policy,
+        master.protocol_fee_bps,
+        master.premium_tax_bps,
+    )
+}
+
+pub(crate) fn premium_paid_with_deductions(
+    received_premium: u64,
+    protocol_fee: u64,
+    premium_tax: u64,
+) -> std::result::Result<u64, OpenParamError> {
+    // 납입 보험료는 리더 deposit 실제 입금액(transfer fee 차감 후)에 treasury 차감분을 더한 값으로 기록한다.
+    received_premium
+        .checked_add(protocol_fee)
+        .and_then(|v| v.checked_add(premium_tax))
+        .ok_or(OpenParamError::MathOverflow)
+}
+
+pub(crate) fn split_received_premium(
+    received_total: u64,
+    count: u64,
+) -> std::result::Result<Vec<u64>, OpenParamError> {
+    // 배치 이체 실제 입금액을 건수로 균등 분할하고, 나머지는 앞 정책부터 1씩 더한다.
+    if count == 0 {
+        return Err(OpenParamError::InvalidInput);
+    }
+    let base = received_total / count;
+    let remainder = received_total % count;
+    Ok((0..count)
+        .map(|i| if i < remainder { base + 1 } else { base })
+        .collect())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn pay_treasury<'info>(
+    treasury_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    expected_treasury: Pubkey,
+    token_program: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    from: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let treasury_token = treasury_token.ok_or(OpenParamError::InvalidAccountList)?;
+    require!(
+        treasury_token.key() == expected_treasury,
+        OpenParamError::InvalidInput
+    );
+    transfer_checked_received(
+        token_program,
+        from,
+        mint,
+        treasury_token.to_account_info(),
+        authority,
+        signer,
+        amount,
+    )?;
+    Ok(())
+}
+
+pub(crate) fn record_premium_deductions(
+    master: &mut MasterPolicy,
+    protocol_fee: u64,
+    premium_tax: u64,
+) -> Result<()> {
+    master.total_protocol_fees = master
+        .total_protocol_fees
+        .checked_add(protocol_fee)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.total_premium_tax = master
+        .total_premium_tax
+        .checked_add(premium_tax)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(())
+}
+
+pub(crate) fn record_issuance(
+    master: &mut MasterPolicy,
+    count: u64,
+    premium_total: u64,
+) -> Result<()> {
+    let policy_liability = max_policy_liability(&master.payout_tiers, master.cancellation_payout);
+    let liability_total = policy_liability
+        .checked_mul(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    // 발급되는 child 정책이 스냅샷하는 현재 출재 비율로 재보험 예약 몫을 정책별로 쌓는다.
+    let ceded_total = calc_ceded_liability(
+        policy_liability,
+        claim_cession_bps(
+            master.commission_mode,
+            master.ceded_ratio_bps,
+            master.reinsurer_effective_bps,
+        ),
+    )?
+    .checked_mul(count)
+    .ok_or(OpenParamError::MathOverflow)?;
+    check_exposure_limits(
+        master.issued_policy_count,
+        master.max_policy_count,
+        master.outstanding_liability,
+        master.aggregate_liability_limit,
+        count,
+        liability_total,
+    )?;
+    master.outstanding_liability = master
+        .outstanding_liability
+        .checked_add(liability_total)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.ceded_outstanding_liability = master
+        .ceded_outstanding_liability
+        .checked_add(ceded_total)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.issued_policy_count = master
+        .issued_policy_count
+        .checked_add(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.open_policy_count = master
+        .open_policy_count
+        .checked_add(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.total_premium_collected = master
+        .total_premium_collected
+        .checked_add(premium_total)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(())
+}
+
+pub(crate) fn record_release(
+    master: &mut MasterPolicy,
+    count: u64,
+    liability: u64,
+    ceded_liability: u64,
+) -> Result<()> {
+    // 종결된 건은 미정산 건수와 미정산 최대 부담액/재보험 예약 몫에서 뺀다.
+    master.open_policy_count = master
+        .open_policy_count
+        .checked_sub(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.outstanding_liability = master
+        .outstanding_liability
+        .checked_sub(liability)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.ceded_outstanding_liability = master
+        .ceded_outstanding_liability
+        .checked_sub(ceded_liability)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(())
+}
+
+pub(crate) fn release_flight(master: &mut MasterPolicy, flight: &FlightPolicy) -> Result<()> {
+    let ceded_liability = flight_ceded_liability(master.commission_mode, flight)?;
+    record_release(master, 1, flight.max_liability, ceded_liability)
+}
+
+pub(crate) fn flight_ceded_liability(
+    commission_mode: u8,
+    flight: &FlightPolicy,
+) -> std::result::Result<u64, OpenParamError> {
+    // 발급 시 예약한 것과 같은 값을 항공편 약관 스냅샷으로 다시 계산해 해제한다.
+    calc_ceded_liability(
+        flight.max_liability,
+        claim_cession_bps(
+            commission_mode,
+            flight.ceded_ratio_bps,
+            flight.reinsurer_effective_bps,
+        ),
+    )
+}
+
+pub(crate) fn calc_ceded_liability(
+    liability: u64,
+    cession_bps: u16,
+) -> std::result::Result<u64, OpenParamError> {
+    // 청구 정산의 재보험사 몫과 같은 방식(내림)으로 최대 부담액의 출재분을 구한다.
+    Ok(liability
+        .checked_mul(cession_bps as u64)
+        .ok_or(OpenParamError::MathOverflow)?
+        / BPS_DENOM)
+}
+
+pub(crate) fn check_exposure_limits(
+    issued_policy_count: u64,
+    max_policy_count: u64,
+    outstanding_liability: u64,
+    aggregate_liability_limit: u64,
+    count: u64,
+    liability: u64,
+) -> std::result::Result<(), OpenParamError> {
+    // 신규 발급분을 더했을 때 발급 건수/누적 최대 부담 한도를 넘으면 거절한다.
+    let issued = issued_policy_count
+        .checked_add(count)
+        .ok_or(OpenParamError::MathOverflow)?;
+    if issued > max_policy_count {
+        return Err(OpenParamError::PolicyCountLimitExceeded);
+    }
+    let outstanding = outstanding_liability
+        .checked_add(liability)
+        .ok_or(OpenParamError::MathOverflow)?;
+    if outstanding > aggregate_liability_limit {
+        return Err(OpenParamError::ExposureLimitExceeded);
+    }
+    Ok(())
+}
+
+pub(crate) fn verify_pool_capacity<'a>(
+    master: &Account<'a, MasterPolicy>,
+    reinsurer_pool_token: &InterfaceAccount<'a, TokenAccount>,
+    xol_pool_token: Option<&InterfaceAccount<'a, TokenAccount>>,
+    pool_infos: &'a [AccountInfo<'a>],
+) -> Result<()> {
+    require!(
+        reinsurer_pool_token.key() == master.reinsurer_pool_wallet,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        pool_infos.len() == master.participants.len(),
+        OpenParamError::InvalidAccountList
+    );
+
+    let mut pool_balances = Vec::with_capacity(pool_infos.len());
+    for (participant, pool_info) in master.participants.iter().zip(pool_infos) {
+        let pool_wallet: InterfaceAccount<TokenAccount> = InterfaceAccount::try_from(pool_info)?;
+        require!(
+            pool_wallet.key() == participant.pool_wallet,
+            OpenParamError::InvalidInput
+        );
+        pool_balances.push(pool_wallet.amount);
+    }
+
+    let (reinsurer_required, insurer_required, xol_required) = calc_reserved_shares(master)?;
+    if xol_required > 0 {
+        let xol_pool_token = xol_pool_token.ok_or(OpenParamError::InvalidAccountList)?;
+        require!(
+            xol_pool_token.key() == master.xol_pool_wallet,
+            OpenParamError::InvalidInput
+        );
+        require!(
+            xol_pool_token.amount >= xol_required,
+            OpenParamError::InsufficientPoolCapacity
+        );
+    }
+    check_reserve_coverage(
+        reinsurer_required,
+        &insurer_required,
+        reinsurer_pool_token.amount,
+        &pool_balances,
+    )?;
+    Ok(())
+}
+
+pub(crate) fn calc_reserved_shares(
+    master: &MasterPolicy,
+) -> std::result::Result<(u64, Vec<u64>, u64), OpenParamError> {
+    // 재보험 몫은 child 정책별 발급 시점 출재 비율로 쌓은 예약액, 참여사 몫은 나머지를 현재 지분으로 나눈 값.
+    // 정산/무효화/만료 시 항공편 스냅샷 기준으로 같은 금액이 해제된다.
+    let xol_reserved = calc_xol_reserve(
+        master.outstanding_liability,
+        master.open_policy_count,
+        master.xol_retention,
+        master.xol_limit,
+    )?;
+    // XoL 회수액은 청구가 사고별로 어떻게 묶이는지에 따라 0까지 줄 수 있고, stop-loss 회수는
+    // 손해율에 따라 달라지므로 quota share/참여사 예약 몫에서는 차감하지 않는다.
+    let insurer_ratios: Vec<u16> = master.participants.iter().map(|p| p.share_bps).collect();
+    let (reinsurer_reserved, insurer_reserved) = split_reserved_liability(
+        master.outstanding_liability,
+        master.ceded_outstanding_liability,
+        &insurer_ratios,
+    )?;
+    Ok((reinsurer_reserved, insurer_reserved, xol_reserved))
+}
+
+pub(crate) fn split_reserved_liability(
+    outstanding_liability: u64,
+    ceded_outstanding_liability: u64,
+    insurer_share_bps: &[u16],
+) -> std::result::Result<(u64, Vec<u64>), OpenParamError> {
+    let retained = outstanding_liability
+        .checked_sub(ceded_outstanding_liability)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok((
+        ceded_outstanding_liability,
+        split_by_bps(retained, insurer_share_bps)?,
+    ))
+}
+
+pub(crate) fn calc_xol_reserve(
+    outstanding_liability: u64,
+    open_policy_count: u64,
+    xol_retention: u64,
+    xol_limit: u64,
+) -> std::result::Result<u64, OpenParamError> {
+    // XoL은 사고(항공편) 단위로 적용되므로 미정산 건이 E개 사고로 묶일 때의 최대 회수액
+    // min(E × limit, 미정산 부담액 − E × retention)을 E에 대해 최대화한 값을 예약한다.
+    if xol_limit == 0 || open_policy_count == 0 {
+        return Ok(0);
+    }
+    let layer = xol_retention
+        .checked_add(xol_limit)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let pivot = outstanding_liability / layer;
+    let mut reserve = 0u64;
+    for events in [1, pivot, pivot.saturating_add(1), open_policy_count] {
+        let events = events.clamp(1, open_policy_count);
+        let by_limit = xol_limit.saturating_mul(events);
+        let by_loss = outstanding_liability.saturating_sub(xol_retention.saturating_mul(events));
+        reserve = reserve.max(by_limit.min(by_loss));
+    }
+    Ok(reserve)
+}
 
+pub(crate) fn check_reserve_coverage(
+    reinsurer_required: u64,
+    insurer_required: &[u64],
+    reinsurer_balance: u64,
+    pool_balances: &[u64],
+) -> std::result::Result<(), OpenParamError> {
+    // 각 풀 잔액이 자기 예약 몫 이상인지 본다.
+    if reinsurer_balance < reinsurer_required {
+        return Err(OpenParamError::InsufficientPoolCapacity);
+    }
+    if pool_balances.len() != insurer_required.len() {
+        return Err(OpenParamError::InvalidAccountList);
+    }
+    if pool_balances
+        .iter()
+        .zip(insurer_required)
+        .any(|(balance, required)| balance < required)
+    {
+        return Err(OpenParamError::InsufficientPoolCapacity);
+    }
     Ok(())
 }
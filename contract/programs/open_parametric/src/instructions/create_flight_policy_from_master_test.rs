@@ -0,0 +1,193 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::errors::OpenParamError;
+
+use super::create_flight_policy_from_master::{
+    calc_ceded_liability, calc_premium_deductions, calc_xol_reserve, check_beneficiary_token,
+    check_exposure_limits, check_reserve_coverage, premium_paid_with_deductions,
+    split_received_premium, split_reserved_liability, validate_departure,
+};
+use super::settle_flight_claim::calc_claim_split;
+
+const START: i64 = 1_700_000_000;
+const END: i64 = START + 30 * 24 * 60 * 60;
+
+#[test]
+fn departure_inside_window_with_enough_lead_time_is_accepted() {
+    let now = START;
+    assert!(validate_departure(START, END, 3_600, now + 3_600, now).is_ok());
+}
+
+#[test]
+fn departure_outside_coverage_is_rejected() {
+    // 보장 기간 시작 전/종료 후 출발편은 발급할 수 없다.
+    let now = START - 86_400;
+    assert!(matches!(
+        validate_departure(START, END, 0, START - 1, now),
+        Err(OpenParamError::DepartureOutsideCoverage)
+    ));
+    assert!(matches!(
+        validate_departure(START, END, 0, END + 1, now),
+        Err(OpenParamError::DepartureOutsideCoverage)
+    ));
+}
+
+#[test]
+fn departure_past_or_within_cutoff_is_rejected() {
+    // 이미 출발했거나 리드타임보다 임박한 항공편은 거절한다.
+    let now = START + 86_400;
+    assert!(matches!(
+        validate_departure(START, END, 0, now - 60, now),
+        Err(OpenParamError::PurchaseCutoffPassed)
+    ));
+    assert!(matches!(
+        validate_departure(START, END, 3_600, now + 3_599, now),
+        Err(OpenParamError::PurchaseCutoffPassed)
+    ));
+}
+
+#[test]
+fn issuance_up_to_limits_is_accepted() {
+    // 한도와 정확히 같아지는 발급까지는 허용한다.
+    assert!(check_exposure_limits(9, 10, 720, 800, 1, 80).is_ok());
+}
+
+#[test]
+fn issuance_beyond_policy_count_is_rejected() {
+    assert!(matches!(
+        check_exposure_limits(10, 10, 0, 800, 1, 80),
+        Err(OpenParamError::PolicyCountLimitExceeded)
+    ));
+}
+
+#[test]
+fn issuance_beyond_liability_limit_is_rejected() {
+    // 배치 발급분 전체를 합산해 누적 최대 부담 한도를 검사한다.
+    assert!(matches!(
+        check_exposure_limits(0, 100, 720, 800, 2, 160),
+        Err(OpenParamError::ExposureLimitExceeded)
+    ));
+}
+
+#[test]
+fn pools_covering_their_share_of_reserved_liability_pass() {
+    // 예약 몫 재보험 72, 참여사 44/26.4/17.6 USDC를 정확히 보유하면 통과.
+    assert!(check_reserve_coverage(
+        72_000_000,
+        &[44_000_000, 26_400_000, 17_600_000],
+        72_000_000,
+        &[44_000_000, 26_400_000, 17_600_000]
+    )
+    .is_ok());
+}
+
+#[test]
+fn any_underfunded_pool_fails_issuance() {
+    let required = [44_000_000, 26_400_000, 17_600_000];
+    assert!(matches!(
+        check_reserve_coverage(
+            72_000_000,
+            &required,
+            72_000_000,
+            &[44_000_000, 26_399_999, 17_600_000]
+        ),
+        Err(OpenParamError::InsufficientPoolCapacity)
+    ));
+    assert!(matches!(
+        check_reserve_coverage(
+            72_000_000,
+            &required,
+            71_999_999,
+            &[44_000_000, 26_400_000, 17_600_000]
+        ),
+        Err(OpenParamError::InsufficientPoolCapacity)
+    ));
+}
+
+#[test]
+fn xol_reserve_covers_worst_event_grouping() {
+    // 미정산 300 USDC / 3건, retention 60 / limit 30 → 3건이 각각 다른 사고면 30 × 3 = 90.
+    assert_eq!(
+        calc_xol_reserve(300_000_000, 3, 60_000_000, 30_000_000).unwrap(),
+        90_000_000
+    );
+    // 건당 50 USDC × 2건은 건별로는 retention 미만이지만 한 사고로 묶이면 100 − 60 = 40을 회수한다.
+    assert_eq!(
+        calc_xol_reserve(100_000_000, 2, 60_000_000, 100_000_000).unwrap(),
+        40_000_000
+    );
+    assert_eq!(calc_xol_reserve(300_000_000, 3, 0, 0).unwrap(), 0);
+    assert_eq!(calc_xol_reserve(0, 0, 60_000_000, 30_000_000).unwrap(), 0);
+}
+
+#[test]
+fn premium_deductions_round_down_per_rate() {
+    // 보험료 5 USDC에 수수료 2%, 세금 10%를 적용한다.
+    assert_eq!(
+        calc_premium_deductions(5_000_000, 200, 1_000).unwrap(),
+        (100_000, 500_000)
+    );
+    assert_eq!(calc_premium_deductions(5_000_000, 0, 0).unwrap(), (0, 0));
+    // 1 단위 미만은 버려 리더 deposit 쪽에 남긴다.
+    assert_eq!(calc_premium_deductions(99, 100, 0).unwrap(), (0, 0));
+}
+
+#[test]
+fn received_premium_is_recorded_with_treasury_deductions() {
+    // transfer fee로 덜 입금된 보험료에 treasury 차감분을 더해 납입 보험료로 기록한다.
+    assert_eq!(
+        premium_paid_with_deductions(9_405_000, 300_000, 200_000).unwrap(),
+        9_905_000
+    );
+}
+
+#[test]
+fn batch_received_premium_is_split_without_loss() {
+    // 나머지는 앞 정책부터 배분되어 합계가 실제 입금액과 일치한다.
+    let split = split_received_premium(29_700_001, 3).unwrap();
+    assert_eq!(split, vec![9_900_001, 9_900_000, 9_900_000]);
+    assert_eq!(split.iter().sum::<u64>(), 29_700_001);
+    assert!(matches!(
+        split_received_premium(100, 0),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn beneficiary_token_is_checked_at_issuance() {
+    // 직접 지급 대상을 지정하면 같은 key, 같은 통화 mint의 토큰 계정이 함께 전달되어야 한다.
+    let mint = Pubkey::new_unique();
+    let beneficiary = Pubkey::new_unique();
+    assert!(check_beneficiary_token(Pubkey::default(), None, mint).is_ok());
+    assert!(check_beneficiary_token(beneficiary, Some((beneficiary, mint)), mint).is_ok());
+    assert!(matches!(
+        check_beneficiary_token(beneficiary, None, mint),
+        Err(OpenParamError::InvalidAccountList)
+    ));
+    assert!(matches!(
+        check_beneficiary_token(beneficiary, Some((Pubkey::new_unique(), mint)), mint),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(matches!(
+        check_beneficiary_token(beneficiary, Some((beneficiary, Pubkey::new_unique())), mint),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn reinsurer_reserve_follows_issuance_snapshot_across_amendment() {
+    // 출재 50%로 1건(100 USDC) 발급 후 약관 변경으로 20%가 되고 1건 더 발급하면 재보험 예약은 50 + 20.
+    let ratios = [5_000, 3_000, 2_000];
+    let first = calc_ceded_liability(100_000_000, 5_000).unwrap();
+    let second = calc_ceded_liability(100_000_000, 2_000).unwrap();
+    let (reinsurer, insurers) =
+        split_reserved_liability(200_000_000, first + second, &ratios).unwrap();
+    assert_eq!(reinsurer, 70_000_000);
+    assert_eq!(insurers, vec![65_000_000, 39_000_000, 26_000_000]);
+
+    // 첫 건을 정산해 해제하면 남은 예약은 둘째 건의 정산 분할과 정확히 같다.
+    let (reinsurer, insurers) = split_reserved_liability(100_000_000, second, &ratios).unwrap();
+    let split = calc_claim_split(100_000_000, 0, 2_000, &ratios, 0).unwrap();
+    assert_eq!(reinsurer, split.reinsurer_amount);
+    assert_eq!(insurers, split.insurer_amounts);
+}
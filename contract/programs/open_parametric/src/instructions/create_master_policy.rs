@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount};
 
 use crate::constants::*;
 use crate::errors::OpenParamError;
-use crate::math::effective_reinsurer_bps;
+use crate::math::{
+    effective_reinsurer_bps, max_policy_liability, validate_payout_tiers, BPS_DENOM,
+};
 use crate::state::*;
 
 #[derive(Accounts)]
@@ -15,7 +17,9 @@ pub struct CreateMasterPolicy<'info> {
     pub operator: UncheckedAccount<'info>,
     /// CHECK: reinsurer identity account
     pub reinsurer: UncheckedAccount<'info>,
-    pub currency_mint: Account<'info, Mint>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     #[account(
         init,
         payer = leader,
@@ -25,16 +29,30 @@ pub struct CreateMasterPolicy<'info> {
     )]
     pub master_policy: Account<'info, MasterPolicy>,
     #[account(mut)]
-    pub leader_deposit_wallet: Account<'info, TokenAccount>,
+    pub leader_deposit_wallet: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
-    pub reinsurer_pool_wallet: Account<'info, TokenAccount>,
+    pub reinsurer_pool_wallet: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
-    pub reinsurer_deposit_wallet: Account<'info, TokenAccount>,
+    pub reinsurer_deposit_wallet: InterfaceAccount<'info, TokenAccount>,
+    /// 프로토콜 수수료가 설정된 경우 필요(config.fee_treasury 소유, 통화 mint 계정)
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금이 설정된 경우 필요(config.tax_treasury 소유, 통화 mint 계정)
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn handler(ctx: Context<CreateMasterPolicy>, params: CreateMasterPolicyParams) -> Result<()> {
     let master = &mut ctx.accounts.master_policy;
+    let config = &ctx.accounts.protocol_config;
+
+    // 전역 일시정지 중에는 신규 마스터를 만들 수 없고, 통화는 허용 목록의 mint만 쓸 수 있다.
+    require!(!config.paused, OpenParamError::ProtocolPaused);
+    require!(
+        config
+            .allowed_mints
+            .contains(&ctx.accounts.currency_mint.key()),
+        OpenParamError::MintNotAllowed
+    );
 
     // 마스터 계약 생성 시점 기본 유효성 검증.
     require!(
@@ -42,6 +60,50 @@ pub fn handler(ctx: Context<CreateMasterPolicy>, params: CreateMasterPolicyParam
         OpenParamError::InvalidTimeWindow
     );
     require!(params.premium_per_policy > 0, OpenParamError::InvalidAmount);
+    validate_payout_tiers(&params.payout_tiers)?;
+    validate_expiry_settings(params.resolution_timeout_hours, params.expiry_action)?;
+    validate_resolver_quorum(&params.resolvers, params.resolver_threshold)?;
+    require!(
+        (0..=MAX_CHALLENGE_PERIOD_SECS).contains(&params.challenge_period_secs),
+        OpenParamError::InvalidTimeWindow
+    );
+    require!(
+        (0..=MAX_MIN_LEAD_TIME_SECS).contains(&params.min_lead_time_secs),
+        OpenParamError::InvalidTimeWindow
+    );
+    require!(params.max_policy_count > 0, OpenParamError::InvalidAmount);
+    require!(
+        params.pool_margin_bps as u64 <= BPS_DENOM,
+        OpenParamError::InvalidRatio
+    );
+    validate_xol_layer(
+        params.xol_reinsurer,
+        params.xol_pool_wallet,
+        params.xol_retention,
+        params.xol_limit,
+    )?;
+    require!(
+        params.commission_mode == CommissionMode::Legacy as u8
+            || params.commission_mode == CommissionMode::Explicit as u8,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        params.deduction_timing == DeductionTiming::AtIssuance as u8
+            || params.deduction_timing == DeductionTiming::AtNoClaimSettlement as u8,
+        OpenParamError::InvalidInput
+    );
+    validate_stop_loss(
+        params.stop_loss_reinsurer,
+        params.stop_loss_pool_wallet,
+        params.stop_loss_attachment_bps,
+        params.stop_loss_limit,
+    )?;
+    // 한도는 최소 정책 1건의 최대 부담액 이상이어야 한다.
+    require!(
+        params.aggregate_liability_limit
+            >= max_policy_liability(&params.payout_tiers, params.cancellation_payout),
+        OpenParamError::InvalidAmount
+    );
     validate_master_participants(&params.participants, ctx.accounts.leader.key())?;
 
     require!(
@@ -68,10 +130,8 @@ pub fn handler(ctx: Context<CreateMasterPolicy>, params: CreateMasterPolicyParam
     master.coverage_start_ts = params.coverage_start_ts;
     master.coverage_end_ts = params.coverage_end_ts;
     master.premium_per_policy = params.premium_per_policy;
-    master.payout_delay_2h = params.payout_delay_2h;
-    master.payout_delay_3h = params.payout_delay_3h;
-    master.payout_delay_4to5h = params.payout_delay_4to5h;
-    master.payout_delay_6h_or_cancelled = params.payout_delay_6h_or_cancelled;
+    master.payout_tiers = params.payout_tiers;
+    master.cancellation_payout = params.cancellation_payout;
     master.ceded_ratio_bps = params.ceded_ratio_bps;
     master.reins_commission_bps = params.reins_commission_bps;
     master.reinsurer_effective_bps = eff_reinsurer_bps;
@@ -83,6 +143,73 @@ pub fn handler(ctx: Context<CreateMasterPolicy>, params: CreateMasterPolicyParam
     master.status = MasterPolicyStatus::PendingConfirm as u8;
     master.created_at = Clock::get()?.unix_timestamp;
     master.bump = ctx.bumps.master_policy;
+    master.issued_policy_count = 0;
+    master.open_policy_count = 0;
+    master.total_premium_collected = 0;
+    master.total_claims_paid = 0;
+    master.closed_at = 0;
+    master.resolution_timeout_hours = params.resolution_timeout_hours;
+    master.expiry_action = params.expiry_action;
+    master.oracle_only_resolution = params.oracle_only_resolution;
+    master.resolvers = params.resolvers;
+    master.resolver_threshold = params.resolver_threshold;
+    master.challenge_period_secs = params.challenge_period_secs;
+    master.min_lead_time_secs = params.min_lead_time_secs;
+    master.outstanding_liability = 0;
+    master.ceded_outstanding_liability = 0;
+    master.aggregate_liability_limit = params.aggregate_liability_limit;
+    master.max_policy_count = params.max_policy_count;
+    master.pool_margin_bps = params.pool_margin_bps;
+    master.reinsurer_capital_deposited = 0;
+    master.reinsurer_capital_withdrawn = 0;
+    master.xol_reinsurer = params.xol_reinsurer;
+    master.xol_pool_wallet = params.xol_pool_wallet;
+    master.xol_retention = params.xol_retention;
+    master.xol_limit = params.xol_limit;
+    master.xol_capital_deposited = 0;
+    master.xol_capital_withdrawn = 0;
+    master.stop_loss_reinsurer = params.stop_loss_reinsurer;
+    master.stop_loss_pool_wallet = params.stop_loss_pool_wallet;
+    master.stop_loss_attachment_bps = params.stop_loss_attachment_bps;
+    master.stop_loss_limit = params.stop_loss_limit;
+    master.stop_loss_recovered = 0;
+    master.stop_loss_capital_deposited = 0;
+    master.stop_loss_capital_withdrawn = 0;
+    master.earned_premium = 0;
+    master.incurred_claims = 0;
+    master.commission_mode = params.commission_mode;
+    master.protocol_fee_bps = config.protocol_fee_bps;
+    master.premium_tax_bps = config.premium_tax_bps;
+    master.fee_treasury_token = resolve_treasury_token(
+        config.protocol_fee_bps,
+        config.fee_treasury,
+        ctx.accounts
+            .fee_treasury_token
+            .as_ref()
+            .map(|t| (t.key(), t.mint, t.owner)),
+        ctx.accounts.currency_mint.key(),
+    )?;
+    master.tax_treasury_token = resolve_treasury_token(
+        config.premium_tax_bps,
+        config.tax_treasury,
+        ctx.accounts
+            .tax_treasury_token
+            .as_ref()
+            .map(|t| (t.key(), t.mint, t.owner)),
+        ctx.accounts.currency_mint.key(),
+    )?;
+    master.deduction_timing = params.deduction_timing;
+    master.total_protocol_fees = 0;
+    master.total_premium_tax = 0;
+    master.total_ceded_premium = 0;
+    master.total_ceding_commission = 0;
+    master.total_ceded_claims = 0;
+    master.role_grants = Vec::new();
+    master.seed_leader = master.leader;
+    master.pending_leader = Pubkey::default();
+    master.pending_operator = Pubkey::default();
+    master.terms_version = 1;
+    master.predecessor = Pubkey::default();
     // 참여사 목록은 지분/확인여부/정산지갑 정보를 포함한 내부 구조로 변환한다.
     master.participants = params
         .participants
@@ -93,6 +220,8 @@ pub fn handler(ctx: Context<CreateMasterPolicy>, params: CreateMasterPolicyParam
             confirmed: p.insurer == master.leader,
             pool_wallet: Pubkey::default(),
             deposit_wallet: Pubkey::default(),
+            capital_deposited: 0,
+            capital_withdrawn: 0,
         })
         .collect();
 
@@ -127,3 +256,130 @@ pub(crate) fn validate_master_participants(
     }
     Ok(())
 }
+
+pub(crate) fn validate_expiry_settings(
+    resolution_timeout_hours: u16,
+    expiry_action: u8,
+) -> std::result::Result<(), OpenParamError> {
+    // 만료 대기 시간은 1시간 이상 상한 이하, 만료 처리 방식은 정의된 값만 허용한다.
+    if resolution_timeout_hours == 0 || resolution_timeout_hours > MAX_RESOLUTION_TIMEOUT_HOURS {
+        return Err(OpenParamError::InvalidTimeWindow);
+    }
+    if expiry_action != ExpiryAction::DistributeAsNoClaim as u8
+        && expiry_action != ExpiryAction::Refund as u8
+    {
+        return Err(OpenParamError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_resolver_quorum(
+    resolvers: &[Pubkey],
+    threshold: u8,
+) -> std::result::Result<(), OpenParamError> {
+    // resolver 미설정 시 threshold는 0, 설정 시 1..=N이며 중복 resolver는 허용하지 않는다.
+    if resolvers.is_empty() {
+        return if threshold == 0 {
+            Ok(())
+        } else {
+            Err(OpenParamError::InvalidInput)
+        };
+    }
+    if resolvers.len() > MAX_RESOLVERS {
+        return Err(OpenParamError::InvalidInput);
+    }
+    if threshold == 0 || threshold as usize > resolvers.len() {
+        return Err(OpenParamError::InvalidInput);
+    }
+    for (i, r) in resolvers.iter().enumerate() {
+        if resolvers[i + 1..].contains(r) {
+            return Err(OpenParamError::AlreadyExists);
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_xol_layer(
+    xol_reinsurer: Pubkey,
+    xol_pool_wallet: Pubkey,
+    xol_retention: u64,
+    xol_limit: u64,
+) -> std::result::Result<(), OpenParamError> {
+    // limit 0은 XoL 미사용이며, 이때 나머지 XoL 설정도 비어 있어야 한다.
+    if xol_limit == 0 {
+        if xol_reinsurer != Pubkey::default()
+            || xol_pool_wallet != Pubkey::default()
+            || xol_retention != 0
+        {
+            return Err(OpenParamError::InvalidInput);
+        }
+        return Ok(());
+    }
+    if xol_reinsurer == Pubkey::default() || xol_pool_wallet == Pubkey::default() {
+        return Err(OpenParamError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_stop_loss(
+    stop_loss_reinsurer: Pubkey,
+    stop_loss_pool_wallet: Pubkey,
+    attachment_bps: u16,
+    limit: u64,
+) -> std::result::Result<(), OpenParamError> {
+    // limit 0은 stop-loss 미사용이며, 이때 나머지 설정도 비어 있어야 한다.
+    if limit == 0 {
+        if stop_loss_reinsurer != Pubkey::default()
+            || stop_loss_pool_wallet != Pubkey::default()
+            || attachment_bps != 0
+        {
+            return Err(OpenParamError::InvalidInput);
+        }
+        return Ok(());
+    }
+    if stop_loss_reinsurer == Pubkey::default() || stop_loss_pool_wallet == Pubkey::default() {
+        return Err(OpenParamError::InvalidInput);
+    }
+    // 손해율 attachment는 0보다 커야 한다(100% 초과 지점도 허용).
+    if attachment_bps == 0 {
+        return Err(OpenParamError::InvalidRatio);
+    }
+    Ok(())
+}
+
+pub(crate) fn resolve_treasury_token(
+    deduction_bps: u16,
+    treasury: Pubkey,
+    provided: Option<(Pubkey, Pubkey, Pubkey)>,
+    currency_mint: Pubkey,
+) -> std::result::Result<Pubkey, OpenParamError> {
+    // 차감 비율이 0이면 treasury 계정을 쓰지 않는다.
+    if deduction_bps == 0 {
+        return Ok(Pubkey::default());
+    }
+    // 마스터 통화 mint의 config treasury 소유 토큰 계정만 수취 계정으로 지정할 수 있다.
+    let (key, mint, owner) = provided.ok_or(OpenParamError::InvalidAccountList)?;
+    if mint != currency_mint || owner != treasury {
+        return Err(OpenParamError::InvalidInput);
+    }
+    Ok(key)
+}
+
+pub(crate) fn validate_premium_deductions(
+    protocol_fee_bps: u16,
+    premium_tax_bps: u16,
+    fee_treasury: Pubkey,
+    tax_treasury: Pubkey,
+) -> std::result::Result<(), OpenParamError> {
+    // 수수료와 세금을 합쳐 보험료 전액을 넘을 수 없다.
+    if protocol_fee_bps as u64 + premium_tax_bps as u64 > BPS_DENOM {
+        return Err(OpenParamError::InvalidRatio);
+    }
+    // 비율이 0보다 크면 수취 treasury가 지정되어야 하고, 0이면 비워 둔다.
+    if (protocol_fee_bps > 0) != (fee_treasury != Pubkey::default())
+        || (premium_tax_bps > 0) != (tax_treasury != Pubkey::default())
+    {
+        return Err(OpenParamError::InvalidInput);
+    }
+    Ok(())
+}
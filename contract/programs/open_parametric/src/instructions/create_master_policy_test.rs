@@ -1,9 +1,12 @@
 use anchor_lang::prelude::Pubkey;
 
 use crate::errors::OpenParamError;
-use crate::state::MasterParticipantInit;
+use crate::state::{ExpiryAction, MasterParticipantInit};
 
-use super::create_master_policy::validate_master_participants;
+use super::create_master_policy::{
+    resolve_treasury_token, validate_expiry_settings, validate_master_participants,
+    validate_premium_deductions, validate_resolver_quorum, validate_stop_loss, validate_xol_layer,
+};
 
 #[test]
 fn master_participants_require_10000_bps_and_include_leader() {
@@ -60,3 +63,133 @@ fn master_participants_reject_missing_leader_or_invalid_sum() {
         Err(OpenParamError::InvalidRatio)
     ));
 }
+
+#[test]
+fn expiry_settings_require_positive_timeout_and_known_action() {
+    // 만료 대기 시간 0 또는 정의되지 않은 처리 방식은 거절해야 한다.
+    assert!(validate_expiry_settings(72, ExpiryAction::DistributeAsNoClaim as u8).is_ok());
+    assert!(validate_expiry_settings(72, ExpiryAction::Refund as u8).is_ok());
+    assert!(matches!(
+        validate_expiry_settings(0, ExpiryAction::Refund as u8),
+        Err(OpenParamError::InvalidTimeWindow)
+    ));
+    assert!(matches!(
+        validate_expiry_settings(72, 9),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn resolver_quorum_threshold_must_fit_resolver_set() {
+    // resolver 미설정(0/0) 또는 1..=N threshold만 허용하고 중복 resolver는 거절한다.
+    let a = Pubkey::new_unique();
+    let b = Pubkey::new_unique();
+    let c = Pubkey::new_unique();
+    assert!(validate_resolver_quorum(&[], 0).is_ok());
+    assert!(validate_resolver_quorum(&[a, b, c], 2).is_ok());
+    assert!(matches!(
+        validate_resolver_quorum(&[], 1),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(matches!(
+        validate_resolver_quorum(&[a, b], 3),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(matches!(
+        validate_resolver_quorum(&[a, b], 0),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(matches!(
+        validate_resolver_quorum(&[a, b, a], 2),
+        Err(OpenParamError::AlreadyExists)
+    ));
+}
+
+#[test]
+fn xol_layer_requires_reinsurer_and_pool_when_enabled() {
+    let reinsurer = Pubkey::new_unique();
+    let pool = Pubkey::new_unique();
+    assert!(validate_xol_layer(Pubkey::default(), Pubkey::default(), 0, 0).is_ok());
+    assert!(validate_xol_layer(reinsurer, pool, 60_000_000, 100_000_000).is_ok());
+    assert!(matches!(
+        validate_xol_layer(Pubkey::default(), pool, 60_000_000, 100_000_000),
+        Err(OpenParamError::InvalidInput)
+    ));
+    // limit 없이 retention만 지정한 설정은 거절한다.
+    assert!(matches!(
+        validate_xol_layer(Pubkey::default(), Pubkey::default(), 60_000_000, 0),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn stop_loss_requires_parties_and_positive_attachment() {
+    let reinsurer = Pubkey::new_unique();
+    let pool = Pubkey::new_unique();
+    assert!(validate_stop_loss(Pubkey::default(), Pubkey::default(), 0, 0).is_ok());
+    assert!(validate_stop_loss(reinsurer, pool, 12_000, 500_000_000).is_ok());
+    assert!(matches!(
+        validate_stop_loss(reinsurer, pool, 0, 500_000_000),
+        Err(OpenParamError::InvalidRatio)
+    ));
+    assert!(matches!(
+        validate_stop_loss(reinsurer, Pubkey::default(), 8_000, 500_000_000),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn premium_deductions_require_treasury_and_fit_premium() {
+    let fee_treasury = Pubkey::new_unique();
+    let tax_treasury = Pubkey::new_unique();
+    assert!(validate_premium_deductions(0, 0, Pubkey::default(), Pubkey::default()).is_ok());
+    assert!(validate_premium_deductions(200, 1_000, fee_treasury, tax_treasury).is_ok());
+    // 비율만 있고 treasury가 없거나, 합계가 100%를 넘으면 거절한다.
+    assert!(matches!(
+        validate_premium_deductions(200, 0, Pubkey::default(), Pubkey::default()),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(matches!(
+        validate_premium_deductions(6_000, 5_000, fee_treasury, tax_treasury),
+        Err(OpenParamError::InvalidRatio)
+    ));
+}
+
+#[test]
+fn treasury_token_must_match_master_mint_and_config_owner() {
+    let treasury = Pubkey::new_unique();
+    let usdc = Pubkey::new_unique();
+    let token = Pubkey::new_unique();
+    // 차감 비율이 0이면 계정 없이도 비워 둔다.
+    assert_eq!(
+        resolve_treasury_token(0, Pubkey::default(), None, usdc).unwrap(),
+        Pubkey::default()
+    );
+    assert_eq!(
+        resolve_treasury_token(200, treasury, Some((token, usdc, treasury)), usdc).unwrap(),
+        token
+    );
+    assert!(matches!(
+        resolve_treasury_token(200, treasury, None, usdc),
+        Err(OpenParamError::InvalidAccountList)
+    ));
+    // 다른 mint의 treasury 계정이나 다른 소유자의 계정은 거절한다.
+    assert!(matches!(
+        resolve_treasury_token(
+            200,
+            treasury,
+            Some((token, Pubkey::new_unique(), treasury)),
+            usdc
+        ),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(matches!(
+        resolve_treasury_token(
+            200,
+            treasury,
+            Some((token, usdc, Pubkey::new_unique())),
+            usdc
+        ),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
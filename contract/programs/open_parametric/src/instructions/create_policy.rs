@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::*;
 use crate::errors::OpenParamError;
@@ -11,7 +11,9 @@ use crate::state::*;
 pub struct CreatePolicy<'info> {
     #[account(mut)]
     pub leader: Signer<'info>,
-    pub currency_mint: Account<'info, Mint>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
     #[account(
         init,
         payer = leader,
@@ -48,12 +50,13 @@ pub struct CreatePolicy<'info> {
         init,
         payer = leader,
         associated_token::mint = currency_mint,
-        associated_token::authority = risk_pool
+        associated_token::authority = risk_pool,
+        associated_token::token_program = token_program
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(ctx: Context<CreatePolicy>, params: CreatePolicyParams) -> Result<()> {
@@ -74,11 +77,20 @@ pub fn handler(ctx: Context<CreatePolicy>, params: CreatePolicyParams) -> Result
         participants,
     } = params;
 
+    let config = &ctx.accounts.protocol_config;
+    require!(!config.paused, OpenParamError::ProtocolPaused);
+    require!(
+        config
+            .allowed_mints
+            .contains(&ctx.accounts.currency_mint.key()),
+        OpenParamError::MintNotAllowed
+    );
+
     // 정책 생성 입력 검증(기간/금액/지연 임계치/문자열 길이/참여자 지분).
     require!(active_from < active_to, OpenParamError::InvalidTimeWindow);
     require!(payout_amount > 0, OpenParamError::InvalidAmount);
     require!(
-        delay_threshold_min == DELAY_THRESHOLD_MIN,
+        delay_threshold_min == config.delay_threshold_min,
         OpenParamError::InvalidDelayThreshold
     );
     require!(route.len() <= MAX_ROUTE_LEN, OpenParamError::InputTooLong);
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::withdraw_pool_excess::{find_pool_party, PoolParty};
+
+#[derive(Accounts)]
+pub struct DepositPoolCapital<'info> {
+    pub actor: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub pool_wallet: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<DepositPoolCapital>, amount: u64) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    // 종료/취소된 마스터에는 추가 자본을 넣을 수 없다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
+    require!(amount > 0, OpenParamError::InvalidAmount);
+
+    let party = find_pool_party(
+        master,
+        ctx.accounts.actor.key(),
+        ctx.accounts.pool_wallet.key(),
+    )?;
+    require!(
+        ctx.accounts.pool_wallet.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
+    require!(
+        ctx.accounts.source_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+
+    // 본인 토큰 계정에서 마스터 PDA 소유 풀 지갑으로 자본을 납입한다.
+    // 납입 자본은 transfer fee를 뺀 풀 지갑 실제 입금액으로 기록한다.
+    let received = transfer_checked_received(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.source_token.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.pool_wallet.to_account_info(),
+        ctx.accounts.actor.to_account_info(),
+        &[],
+        amount,
+    )?;
+
+    let master = &mut ctx.accounts.master_policy;
+    let deposited = match party {
+        PoolParty::Participant(idx) => &mut master.participants[idx].capital_deposited,
+        PoolParty::Reinsurer => &mut master.reinsurer_capital_deposited,
+        PoolParty::XolReinsurer => &mut master.xol_capital_deposited,
+        PoolParty::StopLossReinsurer => &mut master.stop_loss_capital_deposited,
+    };
+    *deposited = deposited
+        .checked_add(received)
+        .ok_or(OpenParamError::MathOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+use super::create_flight_policy_from_master::{record_premium_deductions, release_flight};
+use super::settle_flight_claim::record_earned_premium;
+use super::settle_flight_no_claim::{
+    collect_premium_deductions, distribute_premium, record_cession,
+};
+use super::void_flight_policy::{record_premium_refund, refund_premium};
+
+#[derive(Accounts)]
+pub struct ExpireFlightPolicy<'info> {
+    // 누구나 호출할 수 있다(만료 조건은 온체인 시각으로만 판단).
+    pub caller: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+    #[account(mut)]
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    /// DistributeAsNoClaim 처리 시 필요
+    #[account(mut)]
+    pub reinsurer_deposit_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// Refund 처리 시 필요
+    #[account(mut)]
+    pub payer_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 프로토콜 수수료 차감 시 필요
+    #[account(mut)]
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금 차감 시 필요
+    #[account(mut)]
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, ExpireFlightPolicy<'a>>) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+
+    // 활성 마스터의 결과 미확정 child 정책만 만료 처리할 수 있다.
+    // 분쟁 중인 정책은 resolve_flight_dispute/expire_flight_dispute로만 분쟁 상태를 벗어난다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        flight.status == FlightPolicyStatus::AwaitingOracle as u8
+            || flight.status == FlightPolicyStatus::Issued as u8,
+        OpenParamError::InvalidState
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        is_flight_expired(flight.departure_ts, master.resolution_timeout_hours, now)?,
+        OpenParamError::InvalidTimeWindow
+    );
+
+    // 마스터 설정에 따라 무청구 정산(보험료 분배) 또는 보험료 환급으로 종결한다.
+    let mut premium_earned = 0;
+    let mut deductions = (0, 0);
+    let mut cession = (0, 0);
+    let mut premium_refunded = 0;
+    if distributes_on_expiry(master.expiry_action) {
+        let reinsurer_deposit_token = ctx
+            .accounts
+            .reinsurer_deposit_token
+            .as_ref()
+            .ok_or(OpenParamError::InvalidAccountList)?;
+        deductions = collect_premium_deductions(
+            master,
+            flight,
+            &ctx.accounts.token_program,
+            &ctx.accounts.currency_mint,
+            &ctx.accounts.leader_deposit_token,
+            ctx.accounts.fee_treasury_token.as_ref(),
+            ctx.accounts.tax_treasury_token.as_ref(),
+        )?;
+        cession = distribute_premium(
+            master,
+            flight,
+            &ctx.accounts.token_program,
+            &ctx.accounts.currency_mint,
+            &ctx.accounts.leader_deposit_token,
+            reinsurer_deposit_token,
+            ctx.remaining_accounts,
+        )?;
+        flight.premium_distributed = true;
+        flight.status = FlightPolicyStatus::Expired as u8;
+        premium_earned = flight.premium_paid;
+    } else {
+        let payer_token = ctx
+            .accounts
+            .payer_token
+            .as_ref()
+            .ok_or(OpenParamError::InvalidAccountList)?;
+        refund_premium(
+            master,
+            flight,
+            &ctx.accounts.token_program,
+            &ctx.accounts.currency_mint,
+            &ctx.accounts.leader_deposit_token,
+            payer_token,
+        )?;
+        flight.status = FlightPolicyStatus::Voided as u8;
+        premium_refunded = flight.premium_paid;
+    }
+    flight.updated_at = now;
+
+    let master = &mut ctx.accounts.master_policy;
+    release_flight(master, &ctx.accounts.flight_policy)?;
+    record_earned_premium(master, premium_earned)?;
+    record_premium_deductions(master, deductions.0, deductions.1)?;
+    record_cession(master, cession.0, cession.1, 0)?;
+    record_premium_refund(master, premium_refunded)?;
+
+    Ok(())
+}
+
+pub(crate) fn calc_expiry_ts(
+    departure_ts: i64,
+    resolution_timeout_hours: u16,
+) -> std::result::Result<i64, OpenParamError> {
+    // 출발 예정 시각 + 마스터 설정 대기 시간(시간 단위)을 만료 시각으로 본다.
+    departure_ts
+        .checked_add(resolution_timeout_hours as i64 * 3_600)
+        .ok_or(OpenParamError::MathOverflow)
+}
+
+pub(crate) fn is_flight_expired(
+    departure_ts: i64,
+    resolution_timeout_hours: u16,
+    now: i64,
+) -> std::result::Result<bool, OpenParamError> {
+    // 만료 시각 당일(같은 초)부터 만료 처리할 수 있다.
+    Ok(now >= calc_expiry_ts(departure_ts, resolution_timeout_hours)?)
+}
+
+pub(crate) fn distributes_on_expiry(expiry_action: u8) -> bool {
+    // DistributeAsNoClaim이면 무청구로 보험료를 분배하고, 그 외(Refund)는 납입 계정으로 환급한다.
+    expiry_action == ExpiryAction::DistributeAsNoClaim as u8
+}
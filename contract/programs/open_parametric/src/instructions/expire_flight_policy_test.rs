@@ -0,0 +1,39 @@
+use crate::errors::OpenParamError;
+use crate::state::ExpiryAction;
+
+use super::expire_flight_policy::{calc_expiry_ts, distributes_on_expiry, is_flight_expired};
+
+#[test]
+fn expiry_ts_adds_timeout_hours_to_departure() {
+    // 출발 예정 시각에 대기 시간(시간 단위)을 더한 시각이 만료 시각이다.
+    let departure_ts: i64 = 1_700_000_000;
+    assert_eq!(
+        calc_expiry_ts(departure_ts, 72).unwrap(),
+        departure_ts + 72 * 3_600
+    );
+    assert_eq!(calc_expiry_ts(departure_ts, 0).unwrap(), departure_ts);
+    assert!(matches!(
+        calc_expiry_ts(i64::MAX - 100, 1),
+        Err(OpenParamError::MathOverflow)
+    ));
+}
+
+#[test]
+fn flight_expires_exactly_at_timeout() {
+    // 만료 시각 1초 전까지는 만료 처리할 수 없고, 만료 시각부터 가능하다.
+    let departure_ts: i64 = 1_700_000_000;
+    let expires_at = departure_ts + 72 * 3_600;
+    assert!(!is_flight_expired(departure_ts, 72, departure_ts).unwrap());
+    assert!(!is_flight_expired(departure_ts, 72, expires_at - 1).unwrap());
+    assert!(is_flight_expired(departure_ts, 72, expires_at).unwrap());
+    assert!(is_flight_expired(departure_ts, 72, expires_at + 60).unwrap());
+}
+
+#[test]
+fn expiry_action_selects_no_claim_or_refund() {
+    // DistributeAsNoClaim만 무청구 분배, Refund는 보험료 환급으로 종결한다.
+    assert!(distributes_on_expiry(
+        ExpiryAction::DistributeAsNoClaim as u8
+    ));
+    assert!(!distributes_on_expiry(ExpiryAction::Refund as u8));
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::OpenParamError;
 use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
 
 // ─── Expire ───────────────────────────────────────────────────────────────────
 
@@ -39,12 +40,13 @@ pub struct RefundAfterExpiry<'info> {
     #[account(mut, seeds = [b"pool", policy.key().as_ref()], bump = risk_pool.bump)]
     pub risk_pool: Account<'info, RiskPool>,
     #[account(mut)]
-    pub vault: Account<'info, TokenAccount>,
+    pub vault: InterfaceAccount<'info, TokenAccount>,
     #[account(mut)]
-    pub participant_token: Account<'info, TokenAccount>,
+    pub participant_token: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
     #[account(mut, seeds = [b"underwriting", policy.key().as_ref()], bump = underwriting.bump)]
     pub underwriting: Account<'info, Underwriting>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn refund_handler(ctx: Context<RefundAfterExpiry>, share_index: u8) -> Result<()> {
@@ -80,6 +82,10 @@ pub fn refund_handler(ctx: Context<RefundAfterExpiry>, share_index: u8) -> Resul
         ctx.accounts.participant_token.mint == policy.currency_mint,
         OpenParamError::InvalidInput
     );
+    require!(
+        ctx.accounts.currency_mint.key() == policy.currency_mint,
+        OpenParamError::InvalidInput
+    );
 
     let policy_key = policy.key();
     let seeds = &[
@@ -89,16 +95,15 @@ pub fn refund_handler(ctx: Context<RefundAfterExpiry>, share_index: u8) -> Resul
     ];
     let signer = &[&seeds[..]];
     // RiskPool PDA 서명으로 vault -> 참여사 토큰계정 환급 이체를 수행한다.
-    let cpi_ctx = CpiContext::new_with_signer(
+    transfer_checked_received(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.vault.to_account_info(),
-            to: ctx.accounts.participant_token.to_account_info(),
-            authority: ctx.accounts.risk_pool.to_account_info(),
-        },
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.participant_token.to_account_info(),
+        ctx.accounts.risk_pool.to_account_info(),
         signer,
-    );
-    token::transfer(cpi_ctx, share.escrowed_amount)?;
+        share.escrowed_amount,
+    )?;
 
     ctx.accounts.risk_pool.available_balance = ctx
         .accounts
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct FileFlightDispute<'info> {
+    pub disputer: Signer<'info>,
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+}
+
+pub fn handler(ctx: Context<FileFlightDispute>) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+    let disputer = ctx.accounts.disputer.key();
+
+    // 이의 제기는 마스터 참여사 또는 재보험사만 할 수 있다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        disputer == master.reinsurer || master.participants.iter().any(|p| p.insurer == disputer),
+        OpenParamError::Unauthorized
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        flight.status == FlightPolicyStatus::Claimable as u8
+            || flight.status == FlightPolicyStatus::NoClaim as u8,
+        OpenParamError::InvalidState
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        is_within_challenge_window(flight.challenge_deadline, now),
+        OpenParamError::ChallengePeriodEnded
+    );
+
+    // 분쟁 중인 정책은 재확정(resolve_flight_dispute) 전까지 정산이 동결된다.
+    flight.pre_dispute_status = flight.status;
+    flight.status = FlightPolicyStatus::Disputed as u8;
+    flight.disputed_by = disputer;
+    flight.disputed_at = now;
+    flight.updated_at = now;
+
+    Ok(())
+}
+
+pub(crate) fn calc_challenge_deadline(resolved_at: i64, challenge_period_secs: i64) -> i64 {
+    // 확정 시점부터 마스터 설정 기간 동안 이의 제기를 받는다.
+    resolved_at.saturating_add(challenge_period_secs)
+}
+
+pub(crate) fn is_within_challenge_window(challenge_deadline: i64, now: i64) -> bool {
+    // 마감 시각 전까지만 이의 제기를 받는다.
+    now < challenge_deadline
+}
+
+pub(crate) fn is_challenge_period_over(challenge_deadline: i64, now: i64) -> bool {
+    // 이의 제기 기간이 끝난 뒤에만 정산할 수 있다.
+    !is_within_challenge_window(challenge_deadline, now)
+}
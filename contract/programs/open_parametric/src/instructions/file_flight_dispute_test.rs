@@ -0,0 +1,32 @@
+use super::file_flight_dispute::{
+    calc_challenge_deadline, is_challenge_period_over, is_within_challenge_window,
+};
+
+#[test]
+fn dispute_is_accepted_only_before_deadline() {
+    // 마감 1초 전까지는 이의 제기 가능, 마감 시각부터는 불가.
+    let deadline = 1_700_000_000;
+    assert!(is_within_challenge_window(deadline, deadline - 1));
+    assert!(!is_within_challenge_window(deadline, deadline));
+    assert!(!is_within_challenge_window(deadline, deadline + 60));
+}
+
+#[test]
+fn challenge_deadline_follows_master_period() {
+    // 확정 시각에 마스터 이의 제기 기간을 더한 시각이 마감이고, 그 전에는 정산할 수 없다.
+    let resolved_at: i64 = 1_700_000_000;
+    let deadline = calc_challenge_deadline(resolved_at, 3_600);
+    assert_eq!(deadline, resolved_at + 3_600);
+    assert!(!is_challenge_period_over(deadline, resolved_at));
+    assert!(!is_challenge_period_over(deadline, deadline - 1));
+    assert!(is_challenge_period_over(deadline, deadline));
+}
+
+#[test]
+fn zero_challenge_period_disables_disputes() {
+    // 이의 제기 기간이 0이면 확정 즉시 마감되어 바로 정산할 수 있다.
+    let resolved_at: i64 = 1_700_000_000;
+    let deadline = calc_challenge_deadline(resolved_at, 0);
+    assert!(!is_within_challenge_window(deadline, resolved_at));
+    assert!(is_challenge_period_over(deadline, resolved_at));
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct ManageMasterRoles<'info> {
+    pub leader: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+}
+
+pub fn grant_handler(ctx: Context<ManageMasterRoles>, member: Pubkey, role: u8) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+    // 역할 부여/회수는 리더만 할 수 있고, 종료/취소된 마스터는 변경하지 않는다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8
+            && master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::InvalidState
+    );
+    require!(
+        ctx.accounts.leader.key() == master.leader,
+        OpenParamError::Unauthorized
+    );
+    require!(member != Pubkey::default(), OpenParamError::InvalidInput);
+    grant_role(&mut master.role_grants, member, parse_role(role)?)?;
+    Ok(())
+}
+
+pub fn revoke_handler(ctx: Context<ManageMasterRoles>, member: Pubkey, role: u8) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8
+            && master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::InvalidState
+    );
+    require!(
+        ctx.accounts.leader.key() == master.leader,
+        OpenParamError::Unauthorized
+    );
+    revoke_role(&mut master.role_grants, member, parse_role(role)?)?;
+    Ok(())
+}
+
+pub(crate) fn parse_role(role: u8) -> std::result::Result<MasterRole, OpenParamError> {
+    match role {
+        r if r == MasterRole::Issuer as u8 => Ok(MasterRole::Issuer),
+        r if r == MasterRole::Resolver as u8 => Ok(MasterRole::Resolver),
+        r if r == MasterRole::Settler as u8 => Ok(MasterRole::Settler),
+        _ => Err(OpenParamError::InvalidInput),
+    }
+}
+
+pub(crate) fn grant_role(
+    grants: &mut Vec<RoleGrant>,
+    member: Pubkey,
+    role: MasterRole,
+) -> std::result::Result<(), OpenParamError> {
+    let bit = 1u8 << role as u8;
+    if let Some(grant) = grants.iter_mut().find(|g| g.member == member) {
+        if grant.roles & bit != 0 {
+            return Err(OpenParamError::AlreadyExists);
+        }
+        grant.roles |= bit;
+        return Ok(());
+    }
+    if grants.len() >= MAX_ROLE_GRANTS {
+        return Err(OpenParamError::InputTooLong);
+    }
+    grants.push(RoleGrant { member, roles: bit });
+    Ok(())
+}
+
+pub(crate) fn revoke_role(
+    grants: &mut Vec<RoleGrant>,
+    member: Pubkey,
+    role: MasterRole,
+) -> std::result::Result<(), OpenParamError> {
+    // 마지막 역할까지 회수되면 목록에서 계정을 제거한다.
+    let bit = 1u8 << role as u8;
+    let index = grants
+        .iter()
+        .position(|g| g.member == member && g.roles & bit != 0)
+        .ok_or(OpenParamError::InvalidInput)?;
+    grants[index].roles &= !bit;
+    if grants[index].roles == 0 {
+        grants.remove(index);
+    }
+    Ok(())
+}
+
+pub(crate) fn has_master_role(master: &MasterPolicy, signer: Pubkey, role: MasterRole) -> bool {
+    // leader/operator는 모든 역할을 가진 것으로 본다.
+    if signer == master.leader || signer == master.operator {
+        return true;
+    }
+    let bit = 1u8 << role as u8;
+    master
+        .role_grants
+        .iter()
+        .any(|g| g.member == signer && g.roles & bit != 0)
+}
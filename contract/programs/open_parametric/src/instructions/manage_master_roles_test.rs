@@ -0,0 +1,57 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::MAX_ROLE_GRANTS;
+use crate::errors::OpenParamError;
+use crate::state::{MasterRole, RoleGrant};
+
+use super::manage_master_roles::{grant_role, parse_role, revoke_role};
+
+#[test]
+fn roles_are_granted_per_member_as_bits() {
+    // 같은 계정에 여러 역할을 부여하면 한 항목의 비트로 합쳐진다.
+    let bot = Pubkey::new_unique();
+    let mut grants = Vec::new();
+    grant_role(&mut grants, bot, MasterRole::Issuer).unwrap();
+    grant_role(&mut grants, bot, MasterRole::Settler).unwrap();
+    assert_eq!(
+        grants,
+        vec![RoleGrant {
+            member: bot,
+            roles: 0b101
+        }]
+    );
+    assert!(matches!(
+        grant_role(&mut grants, bot, MasterRole::Issuer),
+        Err(OpenParamError::AlreadyExists)
+    ));
+}
+
+#[test]
+fn revoking_last_role_removes_member() {
+    let bot = Pubkey::new_unique();
+    let mut grants = Vec::new();
+    grant_role(&mut grants, bot, MasterRole::Resolver).unwrap();
+    grant_role(&mut grants, bot, MasterRole::Settler).unwrap();
+    revoke_role(&mut grants, bot, MasterRole::Resolver).unwrap();
+    assert_eq!(grants[0].roles, 0b100);
+    revoke_role(&mut grants, bot, MasterRole::Settler).unwrap();
+    assert!(grants.is_empty());
+    // 부여되지 않은 역할은 회수할 수 없다.
+    assert!(matches!(
+        revoke_role(&mut grants, bot, MasterRole::Settler),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn role_list_is_bounded_and_roles_are_known() {
+    let mut grants = Vec::new();
+    for _ in 0..MAX_ROLE_GRANTS {
+        grant_role(&mut grants, Pubkey::new_unique(), MasterRole::Issuer).unwrap();
+    }
+    assert!(matches!(
+        grant_role(&mut grants, Pubkey::new_unique(), MasterRole::Issuer),
+        Err(OpenParamError::InputTooLong)
+    ));
+    assert!(matches!(parse_role(3), Err(OpenParamError::InvalidInput)));
+}
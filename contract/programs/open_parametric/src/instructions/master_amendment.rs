@@ -0,0 +1,205 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::math::{effective_reinsurer_bps, max_policy_liability, validate_payout_tiers};
+use crate::state::*;
+
+// ─── Propose ──────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+#[instruction(params: MasterAmendmentParams)]
+pub struct ProposeMasterAmendment<'info> {
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(
+        init,
+        payer = leader,
+        space = MASTER_AMENDMENT_SPACE,
+        seeds = [b"master_amendment", master_policy.key().as_ref(), &params.amendment_id.to_le_bytes()],
+        bump
+    )]
+    pub amendment: Account<'info, MasterAmendment>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn propose_handler(
+    ctx: Context<ProposeMasterAmendment>,
+    params: MasterAmendmentParams,
+) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    // 활성 마스터의 약관 변경은 리더가 제안하고, 리더 승인은 제안과 함께 기록된다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        ctx.accounts.leader.key() == master.leader,
+        OpenParamError::Unauthorized
+    );
+    let now = Clock::get()?.unix_timestamp;
+    validate_amendment_terms(
+        master.coverage_start_ts,
+        master.aggregate_liability_limit,
+        &params,
+        now,
+    )?;
+
+    let amendment = &mut ctx.accounts.amendment;
+    amendment.master = master.key();
+    amendment.amendment_id = params.amendment_id;
+    amendment.base_terms_version = master.terms_version;
+    amendment.proposer = master.leader;
+    amendment.premium_per_policy = params.premium_per_policy;
+    amendment.payout_tiers = params.payout_tiers;
+    amendment.cancellation_payout = params.cancellation_payout;
+    amendment.ceded_ratio_bps = params.ceded_ratio_bps;
+    amendment.reins_commission_bps = params.reins_commission_bps;
+    amendment.coverage_end_ts = params.coverage_end_ts;
+    amendment.approvals = if master
+        .participants
+        .iter()
+        .any(|p| p.insurer == master.leader)
+    {
+        vec![master.leader]
+    } else {
+        Vec::new()
+    };
+    amendment.applied = false;
+    amendment.created_at = now;
+    amendment.bump = ctx.bumps.amendment;
+
+    Ok(())
+}
+
+// ─── Approve ──────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct ApproveMasterAmendment<'info> {
+    pub actor: Signer<'info>,
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub amendment: Account<'info, MasterAmendment>,
+}
+
+pub fn approve_handler(ctx: Context<ApproveMasterAmendment>) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let amendment = &mut ctx.accounts.amendment;
+    let actor = ctx.accounts.actor.key();
+
+    // confirm_master와 같이 참여사 본인 또는 지정 재보험사만 승인할 수 있다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        amendment.master == master.key(),
+        OpenParamError::InvalidInput
+    );
+    require!(!amendment.applied, OpenParamError::InvalidState);
+    require!(
+        amendment.base_terms_version == master.terms_version,
+        OpenParamError::InvalidState
+    );
+    require!(
+        actor == master.reinsurer || master.participants.iter().any(|p| p.insurer == actor),
+        OpenParamError::Unauthorized
+    );
+    require!(
+        !amendment.approvals.contains(&actor),
+        OpenParamError::AlreadyExists
+    );
+    amendment.approvals.push(actor);
+
+    Ok(())
+}
+
+// ─── Apply ────────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct ApplyMasterAmendment<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub amendment: Account<'info, MasterAmendment>,
+}
+
+pub fn apply_handler(ctx: Context<ApplyMasterAmendment>) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+    let amendment = &mut ctx.accounts.amendment;
+
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        ctx.accounts.authority.key() == master.leader
+            || ctx.accounts.authority.key() == master.operator,
+        OpenParamError::Unauthorized
+    );
+    require!(
+        amendment.master == master.key(),
+        OpenParamError::InvalidInput
+    );
+    require!(!amendment.applied, OpenParamError::InvalidState);
+    // 제안 이후 다른 변경이 먼저 적용되었으면 낡은 제안은 적용하지 않는다.
+    require!(
+        amendment.base_terms_version == master.terms_version,
+        OpenParamError::InvalidState
+    );
+    require!(
+        is_fully_approved(&amendment.approvals, &master.participants, master.reinsurer),
+        OpenParamError::InvalidState
+    );
+
+    // 새 약관은 이후 발급되는 child 정책부터 적용된다(기존 정책은 발급 시점 스냅샷 유지).
+    master.premium_per_policy = amendment.premium_per_policy;
+    master.payout_tiers = amendment.payout_tiers.clone();
+    master.cancellation_payout = amendment.cancellation_payout;
+    master.ceded_ratio_bps = amendment.ceded_ratio_bps;
+    master.reins_commission_bps = amendment.reins_commission_bps;
+    master.reinsurer_effective_bps =
+        effective_reinsurer_bps(amendment.ceded_ratio_bps, amendment.reins_commission_bps)?;
+    master.coverage_end_ts = amendment.coverage_end_ts;
+    master.terms_version = master
+        .terms_version
+        .checked_add(1)
+        .ok_or(OpenParamError::MathOverflow)?;
+    amendment.applied = true;
+
+    Ok(())
+}
+
+pub(crate) fn validate_amendment_terms(
+    coverage_start_ts: i64,
+    aggregate_liability_limit: u64,
+    params: &MasterAmendmentParams,
+    now: i64,
+) -> std::result::Result<(), OpenParamError> {
+    // 마스터 생성 시와 같은 기준으로 새 약관을 검증한다.
+    if params.premium_per_policy == 0 {
+        return Err(OpenParamError::InvalidAmount);
+    }
+    validate_payout_tiers(&params.payout_tiers)?;
+    effective_reinsurer_bps(params.ceded_ratio_bps, params.reins_commission_bps)?;
+    if params.coverage_end_ts <= coverage_start_ts || params.coverage_end_ts < now {
+        return Err(OpenParamError::InvalidTimeWindow);
+    }
+    if aggregate_liability_limit
+        < max_policy_liability(&params.payout_tiers, params.cancellation_payout)
+    {
+        return Err(OpenParamError::InvalidAmount);
+    }
+    Ok(())
+}
+
+pub(crate) fn is_fully_approved(
+    approvals: &[Pubkey],
+    participants: &[MasterParticipant],
+    reinsurer: Pubkey,
+) -> bool {
+    // 적용 시점의 모든 참여사와 재보험사가 승인해야 한다.
+    approvals.contains(&reinsurer) && participants.iter().all(|p| approvals.contains(&p.insurer))
+}
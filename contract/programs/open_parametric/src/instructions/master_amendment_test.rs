@@ -0,0 +1,71 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::errors::OpenParamError;
+use crate::state::{MasterAmendmentParams, MasterParticipant, PayoutTier};
+
+use super::master_amendment::{is_fully_approved, validate_amendment_terms};
+
+fn params(coverage_end_ts: i64) -> MasterAmendmentParams {
+    MasterAmendmentParams {
+        amendment_id: 1,
+        premium_per_policy: 6_000_000,
+        payout_tiers: vec![PayoutTier {
+            min_delay_minutes: 180,
+            payout: 100_000_000,
+        }],
+        cancellation_payout: 100_000_000,
+        ceded_ratio_bps: 4_000,
+        reins_commission_bps: 1_000,
+        coverage_end_ts,
+    }
+}
+
+fn participant(insurer: Pubkey) -> MasterParticipant {
+    MasterParticipant {
+        insurer,
+        share_bps: 5_000,
+        confirmed: true,
+        pool_wallet: Pubkey::new_unique(),
+        deposit_wallet: Pubkey::new_unique(),
+        capital_deposited: 0,
+        capital_withdrawn: 0,
+    }
+}
+
+#[test]
+fn amendment_terms_follow_master_creation_rules() {
+    let now = 1_700_000_000;
+    assert!(validate_amendment_terms(now - 3_600, 500_000_000, &params(now + 86_400), now).is_ok());
+    // 이미 지난 종료 시각이나 기존 한도를 넘는 최대 지급액은 거절한다.
+    assert!(matches!(
+        validate_amendment_terms(now - 3_600, 500_000_000, &params(now - 1), now),
+        Err(OpenParamError::InvalidTimeWindow)
+    ));
+    assert!(matches!(
+        validate_amendment_terms(now - 3_600, 50_000_000, &params(now + 86_400), now),
+        Err(OpenParamError::InvalidAmount)
+    ));
+}
+
+#[test]
+fn every_participant_and_reinsurer_must_approve() {
+    let leader = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let reinsurer = Pubkey::new_unique();
+    let participants = vec![participant(leader), participant(other)];
+    assert!(!is_fully_approved(
+        &[leader, other],
+        &participants,
+        reinsurer
+    ));
+    assert!(!is_fully_approved(
+        &[leader, reinsurer],
+        &participants,
+        reinsurer
+    ));
+    assert!(is_fully_approved(
+        &[leader, reinsurer, other],
+        &participants,
+        reinsurer
+    ));
+}
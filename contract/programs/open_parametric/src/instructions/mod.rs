@@ -3,19 +3,38 @@ pub mod accept_share;
 pub mod activate_master;
 pub mod activate_policy;
 pub mod approve_settle_claim;
+pub mod cancel_master_policy;
 pub mod check_oracle;
+pub mod close_master_policy;
 pub mod confirm_master;
+pub mod create_flight_policies_batch;
 pub mod create_flight_policy_from_master;
 pub mod create_master_policy;
 pub mod create_policy;
+pub mod deposit_pool_capital;
+pub mod expire_flight_policy;
 pub mod expire_refund;
+pub mod file_flight_dispute;
+pub mod manage_master_roles;
+pub mod master_amendment;
 pub mod open_underwriting;
+pub mod protocol_admin;
+pub mod protocol_config;
 pub mod register_participant_wallets;
 pub mod register_policyholder;
 pub mod reject_share;
+pub mod renew_master_policy;
 pub mod resolve_flight_delay;
+pub mod resolve_flight_delay_with_quote;
+pub mod resolve_flight_dispute;
 pub mod settle_flight_claim;
 pub mod settle_flight_no_claim;
+pub mod settle_flights_batch;
+pub mod submit_flight_observation;
+pub mod transfer_master_authority;
+pub mod transfer_participant_share;
+pub mod void_flight_policy;
+pub mod withdraw_pool_excess;
 
 // 인스트럭션별 단위 테스트 모듈
 #[cfg(test)]
@@ -23,13 +42,47 @@ mod accept_share_test;
 #[cfg(test)]
 mod activate_master_test;
 #[cfg(test)]
+mod close_master_policy_test;
+#[cfg(test)]
+mod create_flight_policies_batch_test;
+#[cfg(test)]
+mod create_flight_policy_from_master_test;
+#[cfg(test)]
 mod create_master_policy_test;
 #[cfg(test)]
 mod create_policy_test;
 #[cfg(test)]
+mod expire_flight_policy_test;
+#[cfg(test)]
+mod file_flight_dispute_test;
+#[cfg(test)]
+mod manage_master_roles_test;
+#[cfg(test)]
+mod master_amendment_test;
+#[cfg(test)]
+mod protocol_config_test;
+#[cfg(test)]
+mod renew_master_policy_test;
+#[cfg(test)]
+mod resolve_flight_delay_with_quote_test;
+#[cfg(test)]
+mod resolve_flight_dispute_test;
+#[cfg(test)]
 mod settle_flight_claim_test;
 #[cfg(test)]
 mod settle_flight_no_claim_test;
+#[cfg(test)]
+mod settle_flights_batch_test;
+#[cfg(test)]
+mod submit_flight_observation_test;
+#[cfg(test)]
+mod transfer_master_authority_test;
+#[cfg(test)]
+mod transfer_participant_share_test;
+#[cfg(test)]
+mod void_flight_policy_test;
+#[cfg(test)]
+mod withdraw_pool_excess_test;
 
 #[allow(ambiguous_glob_reexports)]
 pub use accept_share::*;
@@ -40,28 +93,66 @@ pub use activate_policy::*;
 #[allow(ambiguous_glob_reexports)]
 pub use approve_settle_claim::*;
 #[allow(ambiguous_glob_reexports)]
+pub use cancel_master_policy::*;
+#[allow(ambiguous_glob_reexports)]
 pub use check_oracle::*;
 #[allow(ambiguous_glob_reexports)]
+pub use close_master_policy::*;
+#[allow(ambiguous_glob_reexports)]
 pub use confirm_master::*;
 #[allow(ambiguous_glob_reexports)]
+pub use create_flight_policies_batch::*;
+#[allow(ambiguous_glob_reexports)]
 pub use create_flight_policy_from_master::*;
 #[allow(ambiguous_glob_reexports)]
 pub use create_master_policy::*;
 #[allow(ambiguous_glob_reexports)]
 pub use create_policy::*;
 #[allow(ambiguous_glob_reexports)]
+pub use deposit_pool_capital::*;
+#[allow(ambiguous_glob_reexports)]
+pub use expire_flight_policy::*;
+#[allow(ambiguous_glob_reexports)]
 pub use expire_refund::*;
 #[allow(ambiguous_glob_reexports)]
+pub use file_flight_dispute::*;
+#[allow(ambiguous_glob_reexports)]
+pub use manage_master_roles::*;
+#[allow(ambiguous_glob_reexports)]
+pub use master_amendment::*;
+#[allow(ambiguous_glob_reexports)]
 pub use open_underwriting::*;
 #[allow(ambiguous_glob_reexports)]
+pub use protocol_admin::*;
+#[allow(ambiguous_glob_reexports)]
+pub use protocol_config::*;
+#[allow(ambiguous_glob_reexports)]
 pub use register_participant_wallets::*;
 #[allow(ambiguous_glob_reexports)]
 pub use register_policyholder::*;
 #[allow(ambiguous_glob_reexports)]
 pub use reject_share::*;
 #[allow(ambiguous_glob_reexports)]
+pub use renew_master_policy::*;
+#[allow(ambiguous_glob_reexports)]
 pub use resolve_flight_delay::*;
 #[allow(ambiguous_glob_reexports)]
+pub use resolve_flight_delay_with_quote::*;
+#[allow(ambiguous_glob_reexports)]
+pub use resolve_flight_dispute::*;
+#[allow(ambiguous_glob_reexports)]
 pub use settle_flight_claim::*;
 #[allow(ambiguous_glob_reexports)]
 pub use settle_flight_no_claim::*;
+#[allow(ambiguous_glob_reexports)]
+pub use settle_flights_batch::*;
+#[allow(ambiguous_glob_reexports)]
+pub use submit_flight_observation::*;
+#[allow(ambiguous_glob_reexports)]
+pub use transfer_master_authority::*;
+#[allow(ambiguous_glob_reexports)]
+pub use transfer_participant_share::*;
+#[allow(ambiguous_glob_reexports)]
+pub use void_flight_policy::*;
+#[allow(ambiguous_glob_reexports)]
+pub use withdraw_pool_excess::*;
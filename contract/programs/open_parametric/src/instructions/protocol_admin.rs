@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+// ─── Propose ──────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct ProposeProtocolAdmin<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut, seeds = [b"protocol_config"], bump = protocol_config.bump, has_one = admin)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn propose_handler(ctx: Context<ProposeProtocolAdmin>, new_admin: Pubkey) -> Result<()> {
+    // 새 admin 후보만 기록하고, 후보가 직접 수락해야 권한이 넘어간다.
+    // Pubkey::default()를 제안하면 진행 중인 제안을 철회한다.
+    require!(
+        new_admin != ctx.accounts.admin.key(),
+        OpenParamError::InvalidInput
+    );
+    ctx.accounts.protocol_config.pending_admin = new_admin;
+    Ok(())
+}
+
+// ─── Accept ───────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct AcceptProtocolAdmin<'info> {
+    pub new_admin: Signer<'info>,
+    #[account(mut, seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn accept_handler(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.protocol_config;
+    require!(
+        config.pending_admin != Pubkey::default()
+            && config.pending_admin == ctx.accounts.new_admin.key(),
+        OpenParamError::Unauthorized
+    );
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+    Ok(())
+}
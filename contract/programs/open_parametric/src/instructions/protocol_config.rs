@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+use super::create_master_policy::validate_premium_deductions;
+
+// ─── Initialize ───────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct InitializeProtocolConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = PROTOCOL_CONFIG_SPACE,
+        seeds = [b"protocol_config"],
+        bump
+    )]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ OpenParamError::InvalidInput)]
+    pub program: Program<'info, crate::program::OpenParametric>,
+    /// 배포 후 다른 계정이 먼저 설정을 선점하지 못하도록 업그레이드 권한자만 초기화할 수 있다.
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ OpenParamError::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_handler(
+    ctx: Context<InitializeProtocolConfig>,
+    params: ProtocolConfigParams,
+) -> Result<()> {
+    // 프로그램 전역 설정은 한 번만 생성되며, 프로그램 업그레이드 권한자가 최초 admin이 된다.
+    validate_protocol_config(&params)?;
+    let config = &mut ctx.accounts.protocol_config;
+    config.admin = ctx.accounts.admin.key();
+    config.pending_admin = Pubkey::default();
+    config.paused = false;
+    config.bump = ctx.bumps.protocol_config;
+    apply_protocol_config(config, params);
+    Ok(())
+}
+
+// ─── Update ───────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct UpdateProtocolConfig<'info> {
+    pub admin: Signer<'info>,
+    #[account(mut, seeds = [b"protocol_config"], bump = protocol_config.bump, has_one = admin)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+}
+
+pub fn update_handler(
+    ctx: Context<UpdateProtocolConfig>,
+    params: ProtocolConfigParams,
+) -> Result<()> {
+    // 수수료 설정 변경은 이후 생성되는 마스터에만 적용된다(기존 마스터는 스냅샷 유지).
+    validate_protocol_config(&params)?;
+    apply_protocol_config(&mut ctx.accounts.protocol_config, params);
+    Ok(())
+}
+
+pub fn set_paused_handler(ctx: Context<UpdateProtocolConfig>, paused: bool) -> Result<()> {
+    ctx.accounts.protocol_config.paused = paused;
+    Ok(())
+}
+
+fn apply_protocol_config(config: &mut ProtocolConfig, params: ProtocolConfigParams) {
+    config.allowed_mints = params.allowed_mints;
+    config.allowed_queues = params.allowed_queues;
+    config.oracle_max_staleness_slots = params.oracle_max_staleness_slots;
+    config.delay_threshold_min = params.delay_threshold_min;
+    config.protocol_fee_bps = params.protocol_fee_bps;
+    config.premium_tax_bps = params.premium_tax_bps;
+    config.fee_treasury = params.fee_treasury;
+    config.tax_treasury = params.tax_treasury;
+}
+
+pub(crate) fn validate_protocol_config(
+    params: &ProtocolConfigParams,
+) -> std::result::Result<(), OpenParamError> {
+    if params.allowed_mints.len() > MAX_ALLOWED_MINTS
+        || params.allowed_queues.len() > MAX_ALLOWED_QUEUES
+    {
+        return Err(OpenParamError::InputTooLong);
+    }
+    if has_duplicate(&params.allowed_mints) || has_duplicate(&params.allowed_queues) {
+        return Err(OpenParamError::InvalidInput);
+    }
+    if params.oracle_max_staleness_slots == 0 || params.delay_threshold_min == 0 {
+        return Err(OpenParamError::InvalidInput);
+    }
+    validate_premium_deductions(
+        params.protocol_fee_bps,
+        params.premium_tax_bps,
+        params.fee_treasury,
+        params.tax_treasury,
+    )
+}
+
+fn has_duplicate(keys: &[Pubkey]) -> bool {
+    keys.iter().enumerate().any(|(i, k)| keys[..i].contains(k))
+}
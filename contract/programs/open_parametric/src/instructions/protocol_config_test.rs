@@ -0,0 +1,60 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::constants::MAX_ALLOWED_MINTS;
+use crate::errors::OpenParamError;
+use crate::state::ProtocolConfigParams;
+
+use super::protocol_config::validate_protocol_config;
+
+fn base_params() -> ProtocolConfigParams {
+    ProtocolConfigParams {
+        allowed_mints: vec![Pubkey::new_unique()],
+        allowed_queues: vec![Pubkey::new_unique()],
+        oracle_max_staleness_slots: 150,
+        delay_threshold_min: 120,
+        protocol_fee_bps: 200,
+        premium_tax_bps: 0,
+        fee_treasury: Pubkey::new_unique(),
+        tax_treasury: Pubkey::default(),
+    }
+}
+
+#[test]
+fn protocol_config_accepts_valid_settings() {
+    assert!(validate_protocol_config(&base_params()).is_ok());
+}
+
+#[test]
+fn protocol_config_rejects_invalid_lists_and_limits() {
+    // 허용 목록 길이 초과/중복, 0 슬롯 허용치, treasury 없는 수수료는 거절한다.
+    let mut params = base_params();
+    params.allowed_mints = (0..=MAX_ALLOWED_MINTS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    assert!(matches!(
+        validate_protocol_config(&params),
+        Err(OpenParamError::InputTooLong)
+    ));
+
+    let mut params = base_params();
+    let queue = params.allowed_queues[0];
+    params.allowed_queues.push(queue);
+    assert!(matches!(
+        validate_protocol_config(&params),
+        Err(OpenParamError::InvalidInput)
+    ));
+
+    let mut params = base_params();
+    params.oracle_max_staleness_slots = 0;
+    assert!(matches!(
+        validate_protocol_config(&params),
+        Err(OpenParamError::InvalidInput)
+    ));
+
+    let mut params = base_params();
+    params.fee_treasury = Pubkey::default();
+    assert!(matches!(
+        validate_protocol_config(&params),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::TokenAccount;
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::errors::OpenParamError;
 use crate::state::*;
@@ -10,8 +10,8 @@ pub struct RegisterParticipantWallets<'info> {
     pub insurer: Signer<'info>,
     #[account(mut)]
     pub master_policy: Account<'info, MasterPolicy>,
-    pub pool_wallet: Account<'info, TokenAccount>,
-    pub deposit_wallet: Account<'info, TokenAccount>,
+    pub pool_wallet: InterfaceAccount<'info, TokenAccount>,
+    pub deposit_wallet: InterfaceAccount<'info, TokenAccount>,
 }
 
 pub fn handler(ctx: Context<RegisterParticipantWallets>) -> Result<()> {
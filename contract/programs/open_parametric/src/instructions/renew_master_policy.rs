@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::events::MasterPolicyRenewed;
+use crate::math::{effective_reinsurer_bps, max_policy_liability, validate_payout_tiers};
+use crate::state::*;
+
+use super::create_master_policy::{resolve_treasury_token, validate_stop_loss, validate_xol_layer};
+
+#[derive(Accounts)]
+#[instruction(params: RenewMasterPolicyParams)]
+pub struct RenewMasterPolicy<'info> {
+    #[account(mut)]
+    pub leader: Signer<'info>,
+    pub predecessor: Account<'info, MasterPolicy>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    #[account(
+        init,
+        payer = leader,
+        space = MASTER_POLICY_SPACE,
+        seeds = [b"master_policy", leader.key().as_ref(), &params.master_id.to_le_bytes()],
+        bump
+    )]
+    pub master_policy: Account<'info, MasterPolicy>,
+    pub leader_deposit_wallet: InterfaceAccount<'info, TokenAccount>,
+    pub reinsurer_pool_wallet: InterfaceAccount<'info, TokenAccount>,
+    /// 프로토콜 수수료가 설정된 경우 필요(config.fee_treasury 소유, 통화 mint 계정)
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금이 설정된 경우 필요(config.tax_treasury 소유, 통화 mint 계정)
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts 구성: [새 마스터 PDA 소유 참여사 pool_wallet × N] (이전 마스터 참여사 순서)
+pub fn handler<'a>(
+    ctx: Context<'_, '_, 'a, 'a, RenewMasterPolicy<'a>>,
+    params: RenewMasterPolicyParams,
+) -> Result<()> {
+    let predecessor = &ctx.accounts.predecessor;
+    let config = &ctx.accounts.protocol_config;
+    let new_master = ctx.accounts.master_policy.key();
+
+    // 갱신은 이전 마스터의 현재 리더만 요청할 수 있고, 활성/종료된 마스터만 갱신 대상이다.
+    require!(
+        ctx.accounts.leader.key() == predecessor.leader,
+        OpenParamError::Unauthorized
+    );
+    require!(
+        predecessor.status == MasterPolicyStatus::Active as u8
+            || predecessor.status == MasterPolicyStatus::Closed as u8,
+        OpenParamError::InvalidState
+    );
+    require!(!config.paused, OpenParamError::ProtocolPaused);
+    require!(
+        config.allowed_mints.contains(&predecessor.currency_mint),
+        OpenParamError::MintNotAllowed
+    );
+    validate_renewal_window(
+        predecessor.coverage_end_ts,
+        params.coverage_start_ts,
+        params.coverage_end_ts,
+    )?;
+
+    // 지정하지 않은 약관 항목은 이전 마스터 값을 이어받는다.
+    let premium_per_policy = params
+        .premium_per_policy
+        .unwrap_or(predecessor.premium_per_policy);
+    let payout_tiers = params
+        .payout_tiers
+        .unwrap_or_else(|| predecessor.payout_tiers.clone());
+    let cancellation_payout = params
+        .cancellation_payout
+        .unwrap_or(predecessor.cancellation_payout);
+    let ceded_ratio_bps = params
+        .ceded_ratio_bps
+        .unwrap_or(predecessor.ceded_ratio_bps);
+    let reins_commission_bps = params
+        .reins_commission_bps
+        .unwrap_or(predecessor.reins_commission_bps);
+    let aggregate_liability_limit = params
+        .aggregate_liability_limit
+        .unwrap_or(predecessor.aggregate_liability_limit);
+    let max_policy_count = params
+        .max_policy_count
+        .unwrap_or(predecessor.max_policy_count);
+
+    require!(premium_per_policy > 0, OpenParamError::InvalidAmount);
+    validate_payout_tiers(&payout_tiers)?;
+    require!(max_policy_count > 0, OpenParamError::InvalidAmount);
+    require!(
+        aggregate_liability_limit >= max_policy_liability(&payout_tiers, cancellation_payout),
+        OpenParamError::InvalidAmount
+    );
+    let eff_reinsurer_bps = effective_reinsurer_bps(ceded_ratio_bps, reins_commission_bps)?;
+
+    // XoL/stop-loss 레이어는 유지하되 풀 지갑은 새 마스터 기준으로 다시 지정받는다.
+    validate_xol_layer(
+        predecessor.xol_reinsurer,
+        params.xol_pool_wallet,
+        predecessor.xol_retention,
+        predecessor.xol_limit,
+    )?;
+    validate_stop_loss(
+        predecessor.stop_loss_reinsurer,
+        params.stop_loss_pool_wallet,
+        predecessor.stop_loss_attachment_bps,
+        predecessor.stop_loss_limit,
+    )?;
+
+    // 마스터 PDA가 서명해 출금하는 지갑은 새 마스터 소유로 새로 받아야 한다.
+    for wallet in [
+        &ctx.accounts.leader_deposit_wallet,
+        &ctx.accounts.reinsurer_pool_wallet,
+    ] {
+        require!(
+            wallet.mint == predecessor.currency_mint,
+            OpenParamError::InvalidInput
+        );
+        require!(
+            wallet.owner == new_master,
+            OpenParamError::InvalidSettlementTarget
+        );
+    }
+    require!(
+        ctx.remaining_accounts.len() == predecessor.participants.len(),
+        OpenParamError::InvalidAccountList
+    );
+    let mut pool_wallets = Vec::with_capacity(ctx.remaining_accounts.len());
+    for pool_info in ctx.remaining_accounts.iter() {
+        let pool_wallet: InterfaceAccount<TokenAccount> = InterfaceAccount::try_from(pool_info)?;
+        require!(
+            pool_wallet.mint == predecessor.currency_mint,
+            OpenParamError::InvalidInput
+        );
+        require!(
+            pool_wallet.owner == new_master,
+            OpenParamError::InvalidSettlementTarget
+        );
+        pool_wallets.push(pool_wallet.key());
+    }
+    let participants =
+        renewed_participants(&predecessor.participants, predecessor.leader, &pool_wallets)?;
+
+    // 이전 마스터를 복제한 뒤 새 계약 기간/약관을 적용하고 누적 집계는 모두 초기화한다.
+    let mut master = (*ctx.accounts.predecessor).clone();
+    master.master_id = params.master_id;
+    master.coverage_start_ts = params.coverage_start_ts;
+    master.coverage_end_ts = params.coverage_end_ts;
+    master.premium_per_policy = premium_per_policy;
+    master.payout_tiers = payout_tiers;
+    master.cancellation_payout = cancellation_payout;
+    master.ceded_ratio_bps = ceded_ratio_bps;
+    master.reins_commission_bps = reins_commission_bps;
+    master.reinsurer_effective_bps = eff_reinsurer_bps;
+    master.reinsurer_confirmed = false;
+    master.reinsurer_pool_wallet = ctx.accounts.reinsurer_pool_wallet.key();
+    master.leader_deposit_wallet = ctx.accounts.leader_deposit_wallet.key();
+    master.participants = participants;
+    master.status = MasterPolicyStatus::PendingConfirm as u8;
+    master.created_at = Clock::get()?.unix_timestamp;
+    master.bump = ctx.bumps.master_policy;
+    master.issued_policy_count = 0;
+    master.open_policy_count = 0;
+    master.total_premium_collected = 0;
+    master.total_claims_paid = 0;
+    master.closed_at = 0;
+    master.outstanding_liability = 0;
+    master.ceded_outstanding_liability = 0;
+    master.aggregate_liability_limit = aggregate_liability_limit;
+    master.max_policy_count = max_policy_count;
+    master.reinsurer_capital_deposited = 0;
+    master.reinsurer_capital_withdrawn = 0;
+    master.xol_pool_wallet = params.xol_pool_wallet;
+    master.xol_capital_deposited = 0;
+    master.xol_capital_withdrawn = 0;
+    master.stop_loss_pool_wallet = params.stop_loss_pool_wallet;
+    master.stop_loss_recovered = 0;
+    master.stop_loss_capital_deposited = 0;
+    master.stop_loss_capital_withdrawn = 0;
+    master.earned_premium = 0;
+    master.incurred_claims = 0;
+    master.protocol_fee_bps = config.protocol_fee_bps;
+    master.premium_tax_bps = config.premium_tax_bps;
+    master.fee_treasury_token = resolve_treasury_token(
+        config.protocol_fee_bps,
+        config.fee_treasury,
+        ctx.accounts
+            .fee_treasury_token
+            .as_ref()
+            .map(|t| (t.key(), t.mint, t.owner)),
+        predecessor.currency_mint,
+    )?;
+    master.tax_treasury_token = resolve_treasury_token(
+        config.premium_tax_bps,
+        config.tax_treasury,
+        ctx.accounts
+            .tax_treasury_token
+            .as_ref()
+            .map(|t| (t.key(), t.mint, t.owner)),
+        predecessor.currency_mint,
+    )?;
+    master.total_protocol_fees = 0;
+    master.total_premium_tax = 0;
+    master.total_ceded_premium = 0;
+    master.total_ceding_commission = 0;
+    master.total_ceded_claims = 0;
+    master.seed_leader = master.leader;
+    master.pending_leader = Pubkey::default();
+    master.pending_operator = Pubkey::default();
+    master.terms_version = 1;
+    master.predecessor = predecessor.key();
+    ctx.accounts.master_policy.set_inner(master);
+
+    emit!(MasterPolicyRenewed {
+        predecessor: ctx.accounts.predecessor.key(),
+        master: new_master,
+        master_id: params.master_id,
+        leader: ctx.accounts.leader.key(),
+    });
+    Ok(())
+}
+
+pub(crate) fn validate_renewal_window(
+    predecessor_end_ts: i64,
+    coverage_start_ts: i64,
+    coverage_end_ts: i64,
+) -> std::result::Result<(), OpenParamError> {
+    // 갱신 계약은 이전 보장 기간이 끝난 뒤에 시작해 중복 보장이 생기지 않게 한다.
+    if coverage_start_ts >= coverage_end_ts || coverage_start_ts < predecessor_end_ts {
+        return Err(OpenParamError::InvalidTimeWindow);
+    }
+    Ok(())
+}
+
+pub(crate) fn renewed_participants(
+    previous: &[MasterParticipant],
+    leader: Pubkey,
+    pool_wallets: &[Pubkey],
+) -> std::result::Result<Vec<MasterParticipant>, OpenParamError> {
+    // 지분과 deposit 지갑은 유지하고, 새 풀 지갑을 연결한 뒤 리더 외 참여사는 재확인을 받는다.
+    if previous.len() != pool_wallets.len() {
+        return Err(OpenParamError::InvalidAccountList);
+    }
+    Ok(previous
+        .iter()
+        .zip(pool_wallets)
+        .map(|(p, pool_wallet)| MasterParticipant {
+            insurer: p.insurer,
+            share_bps: p.share_bps,
+            confirmed: p.insurer == leader,
+            pool_wallet: *pool_wallet,
+            deposit_wallet: p.deposit_wallet,
+            capital_deposited: 0,
+            capital_withdrawn: 0,
+        })
+        .collect())
+}
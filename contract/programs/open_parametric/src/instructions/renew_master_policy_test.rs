@@ -0,0 +1,64 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::errors::OpenParamError;
+use crate::state::MasterParticipant;
+
+use super::renew_master_policy::{renewed_participants, validate_renewal_window};
+
+fn participant(share_bps: u16) -> MasterParticipant {
+    MasterParticipant {
+        insurer: Pubkey::new_unique(),
+        share_bps,
+        confirmed: true,
+        pool_wallet: Pubkey::new_unique(),
+        deposit_wallet: Pubkey::new_unique(),
+        capital_deposited: 1_000,
+        capital_withdrawn: 200,
+    }
+}
+
+#[test]
+fn renewed_participants_keep_shares_and_require_reconfirmation() {
+    // 지분/deposit 지갑은 유지되고, 풀 지갑 교체와 함께 리더 외 참여사는 미확인 상태가 된다.
+    let previous = vec![participant(6_000), participant(4_000)];
+    let leader = previous[0].insurer;
+    let pools = vec![Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let renewed = renewed_participants(&previous, leader, &pools).unwrap();
+    assert_eq!(renewed.len(), 2);
+    for (i, p) in renewed.iter().enumerate() {
+        assert_eq!(p.insurer, previous[i].insurer);
+        assert_eq!(p.share_bps, previous[i].share_bps);
+        assert_eq!(p.deposit_wallet, previous[i].deposit_wallet);
+        assert_eq!(p.pool_wallet, pools[i]);
+        assert_eq!(p.capital_deposited, 0);
+        assert_eq!(p.capital_withdrawn, 0);
+    }
+    assert!(renewed[0].confirmed);
+    assert!(!renewed[1].confirmed);
+}
+
+#[test]
+fn renewed_participants_require_pool_wallet_per_participant() {
+    // 새 풀 지갑 수가 참여사 수와 다르면 실패한다.
+    let previous = vec![participant(6_000), participant(4_000)];
+    let leader = previous[0].insurer;
+    assert!(matches!(
+        renewed_participants(&previous, leader, &[Pubkey::new_unique()]),
+        Err(OpenParamError::InvalidAccountList)
+    ));
+}
+
+#[test]
+fn renewal_window_must_follow_predecessor_coverage() {
+    // 이전 보장 종료 이후 시작하는 유효한 기간만 허용한다.
+    assert!(validate_renewal_window(1_000, 1_000, 2_000).is_ok());
+    assert!(matches!(
+        validate_renewal_window(1_000, 999, 2_000),
+        Err(OpenParamError::InvalidTimeWindow)
+    ));
+    assert!(matches!(
+        validate_renewal_window(1_000, 2_000, 2_000),
+        Err(OpenParamError::InvalidTimeWindow)
+    ));
+}
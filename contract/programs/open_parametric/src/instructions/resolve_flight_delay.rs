@@ -1,9 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::OpenParamError;
-use crate::math::{tiered_payout, TierPayouts};
+use crate::math::tiered_payout;
 use crate::state::*;
 
+use super::file_flight_dispute::calc_challenge_deadline;
+use super::manage_master_roles::has_master_role;
+
 #[derive(Accounts)]
 pub struct ResolveFlightDelay<'info> {
     pub resolver: Signer<'info>,
@@ -20,14 +23,29 @@ pub fn handler(
     let master = &ctx.accounts.master_policy;
     let flight = &mut ctx.accounts.flight_policy;
 
-    // 지연 결과 확정은 권한자(leader/operator)만 수행할 수 있다.
+    // 지연 결과 확정은 Resolver 권한자(leader/operator 포함)만 수행할 수 있다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
     require!(
         master.status == MasterPolicyStatus::Active as u8,
         OpenParamError::MasterNotActive
     );
     require!(
-        ctx.accounts.resolver.key() == master.leader
-            || ctx.accounts.resolver.key() == master.operator,
+        !master.oracle_only_resolution,
+        OpenParamError::OracleResolutionRequired
+    );
+    require!(
+        master.resolvers.is_empty(),
+        OpenParamError::ResolverQuorumRequired
+    );
+    require!(
+        has_master_role(master, ctx.accounts.resolver.key(), MasterRole::Resolver),
         OpenParamError::Unauthorized
     );
     require!(flight.master == master.key(), OpenParamError::InvalidInput);
@@ -37,16 +55,30 @@ pub fn handler(
         OpenParamError::InvalidState
     );
 
-    // 지연 구간별 테이블에 따라 payout을 계산한다.
+    apply_flight_resolution(
+        master,
+        flight,
+        delay_minutes,
+        cancelled,
+        Clock::get()?.unix_timestamp,
+    );
+
+    Ok(())
+}
+
+pub(crate) fn apply_flight_resolution(
+    master: &MasterPolicy,
+    flight: &mut FlightPolicy,
+    delay_minutes: u16,
+    cancelled: bool,
+    now: i64,
+) {
+    // 발급 시점 약관의 지연 구간별 테이블에 따라 payout을 계산한다.
     let payout = tiered_payout(
         delay_minutes,
         cancelled,
-        TierPayouts {
-            delay_2h: master.payout_delay_2h,
-            delay_3h: master.payout_delay_3h,
-            delay_4to5h: master.payout_delay_4to5h,
-            delay_6h_or_cancelled: master.payout_delay_6h_or_cancelled,
-        },
+        &flight.payout_tiers,
+        flight.cancellation_payout,
     );
 
     // payout 존재 여부에 따라 Claimable/NoClaim 상태를 결정한다.
@@ -58,7 +90,6 @@ pub fn handler(
     } else {
         FlightPolicyStatus::NoClaim as u8
     };
-    flight.updated_at = Clock::get()?.unix_timestamp;
-
-    Ok(())
+    flight.challenge_deadline = calc_challenge_deadline(now, master.challenge_period_secs);
+    flight.updated_at = now;
 }
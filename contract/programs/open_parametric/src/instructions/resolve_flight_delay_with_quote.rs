@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use switchboard_on_demand::{Instructions, QuoteVerifier, SlotHashes};
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+use super::resolve_flight_delay::apply_flight_resolution;
+
+// 슬롯 경과를 시각으로 환산할 때 쓰는 슬롯당 최대 소요 시간(보수적으로 1초).
+pub(crate) const QUOTE_SLOT_MAX_MS: u64 = 1_000;
+
+#[derive(Accounts)]
+pub struct ResolveFlightDelayWithQuote<'info> {
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+    #[account(seeds = [b"protocol_config"], bump = protocol_config.bump)]
+    pub protocol_config: Account<'info, ProtocolConfig>,
+    /// CHECK: Switchboard oracle queue; key validated against protocol_config.allowed_queues
+    pub queue: UncheckedAccount<'info>,
+    /// CHECK: slot hashes sysvar; validated by SlotHashes sysvar trait
+    pub slot_hashes: Sysvar<'info, SlotHashes>,
+    /// CHECK: instructions sysvar; validated by Instructions sysvar trait
+    pub instructions: Sysvar<'info, Instructions>,
+}
+
+pub fn handler(ctx: Context<ResolveFlightDelayWithQuote>) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+
+    // 검증된 quote만 신뢰하므로 서명 권한 없이 누구나 결과 확정을 요청할 수 있다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    // resolver 합의를 쓰는 마스터는 단일 quote로 결과를 확정할 수 없다.
+    require!(
+        master.resolvers.is_empty(),
+        OpenParamError::ResolverQuorumRequired
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        flight.status == FlightPolicyStatus::AwaitingOracle as u8
+            || flight.status == FlightPolicyStatus::Issued as u8,
+        OpenParamError::InvalidState
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        now >= flight.departure_ts,
+        OpenParamError::InvalidTimeWindow
+    );
+    require!(
+        flight.delay_feed_id != [0u8; 32] && flight.cancel_feed_id != [0u8; 32],
+        OpenParamError::InvalidInput
+    );
+
+    let config = &ctx.accounts.protocol_config;
+    require!(
+        config.allowed_queues.contains(&ctx.accounts.queue.key()),
+        OpenParamError::OracleQueueNotAllowed
+    );
+
+    let oracle_quote = QuoteVerifier::new()
+        .queue(ctx.accounts.queue.to_account_info())
+        .slothash_sysvar(ctx.accounts.slot_hashes.to_account_info())
+        .ix_sysvar(ctx.accounts.instructions.to_account_info())
+        .clock_slot(Clock::get()?.slot)
+        .max_age(config.oracle_max_staleness_slots)
+        .verify_instruction_at(0)
+        .map_err(|_| OpenParamError::OracleStale)?;
+
+    // 스테일 데이터(허용 슬롯 초과)는 즉시 거절한다.
+    let current_slot = Clock::get()?.slot;
+    let staleness = current_slot.saturating_sub(oracle_quote.slot());
+    require!(
+        staleness <= config.oracle_max_staleness_slots,
+        OpenParamError::OracleStale
+    );
+    // 출발 예정 시각 이전에 관측된 quote는 지연/결항 결과로 인정하지 않는다.
+    require!(
+        is_quote_after_departure(flight.departure_ts, now, current_slot, oracle_quote.slot()),
+        OpenParamError::InvalidTimeWindow
+    );
+
+    // 이 항공편에 등록된 feed id와 일치하는 값만 읽는다.
+    let feeds = oracle_quote.feeds();
+    let delay_feed = feeds
+        .iter()
+        .find(|f| f.feed_id() == &flight.delay_feed_id)
+        .ok_or(OpenParamError::OracleFormat)?;
+    let cancel_feed = feeds
+        .iter()
+        .find(|f| f.feed_id() == &flight.cancel_feed_id)
+        .ok_or(OpenParamError::OracleFormat)?;
+
+    let delay_value = delay_feed.value();
+    let delay_minutes = parse_quote_delay_minutes(delay_value.mantissa(), delay_value.scale())?;
+    let cancel_value = cancel_feed.value();
+    let cancelled = parse_quote_cancelled_flag(cancel_value.mantissa(), cancel_value.scale())?;
+
+    apply_flight_resolution(master, flight, delay_minutes, cancelled, now);
+
+    Ok(())
+}
+
+pub(crate) fn is_quote_after_departure(
+    departure_ts: i64,
+    now: i64,
+    current_slot: u64,
+    quote_slot: u64,
+) -> bool {
+    // quote 슬롯부터 현재 슬롯까지 최대로 걸렸을 시간을 빼도 출발 이후여야 한다.
+    let elapsed_slots = current_slot.saturating_sub(quote_slot);
+    let max_age_secs = elapsed_slots
+        .saturating_mul(QUOTE_SLOT_MAX_MS)
+        .div_ceil(1_000)
+        .min(i64::MAX as u64) as i64;
+    now.saturating_sub(max_age_secs) >= departure_ts
+}
+
+pub(crate) fn parse_quote_delay_minutes(
+    mantissa: i128,
+    scale: u32,
+) -> std::result::Result<u16, OpenParamError> {
+    // 지연 값은 "분 단위 정수"만 허용한다(scale=0, 음수 불가, 10분 단위).
+    if scale != 0 || mantissa < 0 || mantissa % 10 != 0 {
+        return Err(OpenParamError::OracleFormat);
+    }
+    u16::try_from(mantissa).map_err(|_| OpenParamError::OracleFormat)
+}
+
+pub(crate) fn parse_quote_cancelled_flag(
+    mantissa: i128,
+    scale: u32,
+) -> std::result::Result<bool, OpenParamError> {
+    // 결항 여부는 0(운항) 또는 1(결항) 정수만 허용한다.
+    match (scale, mantissa) {
+        (0, 0) => Ok(false),
+        (0, 1) => Ok(true),
+        _ => Err(OpenParamError::OracleFormat),
+    }
+}
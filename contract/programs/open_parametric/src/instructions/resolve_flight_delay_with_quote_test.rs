@@ -0,0 +1,78 @@
+use crate::errors::OpenParamError;
+
+use super::resolve_flight_delay_with_quote::{
+    is_quote_after_departure, parse_quote_cancelled_flag, parse_quote_delay_minutes,
+};
+
+#[test]
+fn quote_delay_must_be_whole_minutes_in_10_minute_steps() {
+    // scale=0, 10분 단위 정수만 지연 분으로 인정한다.
+    assert_eq!(parse_quote_delay_minutes(0, 0).unwrap(), 0);
+    assert_eq!(parse_quote_delay_minutes(240, 0).unwrap(), 240);
+    assert!(matches!(
+        parse_quote_delay_minutes(125, 0),
+        Err(OpenParamError::OracleFormat)
+    ));
+    assert!(matches!(
+        parse_quote_delay_minutes(2400, 1),
+        Err(OpenParamError::OracleFormat)
+    ));
+    assert!(matches!(
+        parse_quote_delay_minutes(-10, 0),
+        Err(OpenParamError::OracleFormat)
+    ));
+    assert!(matches!(
+        parse_quote_delay_minutes(70_000, 0),
+        Err(OpenParamError::OracleFormat)
+    ));
+}
+
+#[test]
+fn quote_cancelled_flag_accepts_only_zero_or_one() {
+    // 결항 플래그는 0/1 외의 값을 거절해야 한다.
+    assert!(!parse_quote_cancelled_flag(0, 0).unwrap());
+    assert!(parse_quote_cancelled_flag(1, 0).unwrap());
+    assert!(matches!(
+        parse_quote_cancelled_flag(2, 0),
+        Err(OpenParamError::OracleFormat)
+    ));
+    assert!(matches!(
+        parse_quote_cancelled_flag(10, 1),
+        Err(OpenParamError::OracleFormat)
+    ));
+}
+
+#[test]
+fn quote_taken_before_departure_is_rejected() {
+    // 현재 시각이 출발 이후라도 quote가 출발 전 슬롯에서 관측됐다면 거절한다.
+    let departure_ts: i64 = 1_700_000_000;
+    let current_slot: u64 = 300_000_000;
+
+    // 출발 전에는 최신 quote라도 확정할 수 없다.
+    assert!(!is_quote_after_departure(
+        departure_ts,
+        departure_ts - 1,
+        current_slot,
+        current_slot
+    ));
+    // 출발 30초 후 호출했지만 quote가 150슬롯(최대 150초) 전 관측이면 거절.
+    assert!(!is_quote_after_departure(
+        departure_ts,
+        departure_ts + 30,
+        current_slot,
+        current_slot - 150
+    ));
+    // 슬롯 경과분을 빼도 출발 이후인 quote만 인정한다.
+    assert!(is_quote_after_departure(
+        departure_ts,
+        departure_ts + 150,
+        current_slot,
+        current_slot - 150
+    ));
+    assert!(is_quote_after_departure(
+        departure_ts,
+        departure_ts,
+        current_slot,
+        current_slot
+    ));
+}
@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+use super::expire_flight_policy::calc_expiry_ts;
+use super::resolve_flight_delay::apply_flight_resolution;
+
+#[derive(Accounts)]
+pub struct ResolveFlightDispute<'info> {
+    pub leader: Signer<'info>,
+    pub reinsurer: Signer<'info>,
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+}
+
+pub fn handler(
+    ctx: Context<ResolveFlightDispute>,
+    delay_minutes: u16,
+    cancelled: bool,
+) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+
+    // 분쟁 재확정은 리더와 재보험사의 공동 서명으로만 가능하다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        ctx.accounts.leader.key() == master.leader,
+        OpenParamError::Unauthorized
+    );
+    require!(
+        ctx.accounts.reinsurer.key() == master.reinsurer,
+        OpenParamError::Unauthorized
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        flight.status == FlightPolicyStatus::Disputed as u8,
+        OpenParamError::InvalidState
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    apply_flight_resolution(master, flight, delay_minutes, cancelled, now);
+    // 공동 재확정 결과는 최종값이므로 추가 이의 제기 기간을 두지 않는다.
+    flight.challenge_deadline = now;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExpireFlightDispute<'info> {
+    // 누구나 호출할 수 있다(대기 시간은 온체인 시각으로만 판단).
+    pub caller: Signer<'info>,
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+    // 관측 불일치로 분쟁이 된 정책은 resolver 관측 기록을 함께 넘겨 초기화한다.
+    #[account(
+        mut,
+        seeds = [b"flight_resolution", flight_policy.key().as_ref()],
+        bump = flight_resolution.bump
+    )]
+    pub flight_resolution: Option<Account<'info, FlightResolution>>,
+}
+
+pub fn expire_handler(ctx: Context<ExpireFlightDispute>) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+
+    // 재확정 없이 분쟁 대기 시간이 지난 정책만 분쟁 전 결과로 되돌린다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        flight.status == FlightPolicyStatus::Disputed as u8,
+        OpenParamError::InvalidState
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        is_dispute_expired(flight.disputed_at, master.resolution_timeout_hours, now)?,
+        OpenParamError::InvalidTimeWindow
+    );
+
+    let fallback = dispute_fallback_status(flight.pre_dispute_status)?;
+    // 결과 대기 상태로 돌아가면 resolver들이 다시 관측을 제출할 수 있도록 기록을 비운다.
+    if reopens_observations(fallback) {
+        let resolution = ctx
+            .accounts
+            .flight_resolution
+            .as_mut()
+            .ok_or(OpenParamError::InvalidInput)?;
+        require!(
+            resolution.flight_policy == flight.key(),
+            OpenParamError::InvalidInput
+        );
+        reopen_resolution(resolution);
+    }
+    flight.status = fallback;
+    // 되돌린 결과에는 다시 이의를 제기할 수 없고 바로 정산할 수 있다.
+    flight.challenge_deadline = now;
+    flight.updated_at = now;
+
+    Ok(())
+}
+
+pub(crate) fn is_dispute_expired(
+    disputed_at: i64,
+    resolution_timeout_hours: u16,
+    now: i64,
+) -> std::result::Result<bool, OpenParamError> {
+    // 분쟁 시작 시각부터 마스터 대기 시간이 지나야 만료로 본다.
+    Ok(now >= calc_expiry_ts(disputed_at, resolution_timeout_hours)?)
+}
+
+pub(crate) fn dispute_fallback_status(
+    pre_dispute_status: u8,
+) -> std::result::Result<u8, OpenParamError> {
+    // 확정 결과(Claimable/NoClaim) 또는 결과 대기 상태로만 되돌릴 수 있다.
+    let restorable = [
+        FlightPolicyStatus::Issued as u8,
+        FlightPolicyStatus::AwaitingOracle as u8,
+        FlightPolicyStatus::Claimable as u8,
+        FlightPolicyStatus::NoClaim as u8,
+    ];
+    if !restorable.contains(&pre_dispute_status) {
+        return Err(OpenParamError::InvalidState);
+    }
+    Ok(pre_dispute_status)
+}
+
+pub(crate) fn reopens_observations(fallback_status: u8) -> bool {
+    // 결과 대기 상태로 돌아가는 경우에만 관측 라운드를 새로 연다.
+    fallback_status == FlightPolicyStatus::Issued as u8
+        || fallback_status == FlightPolicyStatus::AwaitingOracle as u8
+}
+
+pub(crate) fn reopen_resolution(resolution: &mut FlightResolution) {
+    // 이전 라운드의 불일치 관측을 지워 같은 resolver의 재제출과 합의를 허용한다.
+    resolution.observations.clear();
+    resolution.finalized = false;
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::errors::OpenParamError;
+use crate::state::{FlightPolicyStatus, FlightResolution, ResolverObservation};
+
+use super::resolve_flight_dispute::{
+    dispute_fallback_status, reopen_resolution, reopens_observations,
+};
+use super::submit_flight_observation::{evaluate_observations, ObservationOutcome};
+
+#[test]
+fn dispute_fallback_restores_pre_dispute_outcome() {
+    // 이의 제기된 청구/무청구 결과는 그대로 복원된다.
+    for status in [FlightPolicyStatus::Claimable, FlightPolicyStatus::NoClaim] {
+        assert_eq!(dispute_fallback_status(status as u8).unwrap(), status as u8);
+    }
+    // 관측 불일치로 분쟁이 된 정책은 결과 대기 상태로 돌아가 만료 처리 대상이 된다.
+    assert_eq!(
+        dispute_fallback_status(FlightPolicyStatus::AwaitingOracle as u8).unwrap(),
+        FlightPolicyStatus::AwaitingOracle as u8
+    );
+}
+
+#[test]
+fn dispute_fallback_rejects_terminal_states() {
+    // 종결/분쟁 상태로는 되돌릴 수 없다.
+    for status in [
+        FlightPolicyStatus::Paid,
+        FlightPolicyStatus::Expired,
+        FlightPolicyStatus::Voided,
+        FlightPolicyStatus::Disputed,
+    ] {
+        assert!(matches!(
+            dispute_fallback_status(status as u8),
+            Err(OpenParamError::InvalidState)
+        ));
+    }
+}
+
+#[test]
+fn fallback_to_awaiting_reopens_resolver_round() {
+    // 관측 불일치 분쟁이 만료되면 기존 관측을 지우고 같은 resolver가 다시 합의할 수 있다.
+    let resolvers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut resolution = FlightResolution {
+        flight_policy: Pubkey::new_unique(),
+        master: Pubkey::new_unique(),
+        observations: vec![
+            ResolverObservation {
+                resolver: resolvers[0],
+                delay_minutes: 240,
+                cancelled: false,
+                submitted_at: 0,
+            },
+            ResolverObservation {
+                resolver: resolvers[1],
+                delay_minutes: 120,
+                cancelled: false,
+                submitted_at: 0,
+            },
+        ],
+        finalized: false,
+        bump: 255,
+    };
+    assert_eq!(
+        evaluate_observations(&resolution.observations, 2, 2),
+        ObservationOutcome::Disputed
+    );
+
+    let fallback = dispute_fallback_status(FlightPolicyStatus::AwaitingOracle as u8).unwrap();
+    assert!(reopens_observations(fallback));
+    reopen_resolution(&mut resolution);
+    assert!(resolution.observations.is_empty());
+    assert!(!resolution.finalized);
+
+    for resolver in resolvers {
+        assert!(!resolution
+            .observations
+            .iter()
+            .any(|o| o.resolver == resolver));
+        resolution.observations.push(ResolverObservation {
+            resolver,
+            delay_minutes: 240,
+            cancelled: false,
+            submitted_at: 1,
+        });
+    }
+    assert_eq!(
+        evaluate_observations(&resolution.observations, 2, 2),
+        ObservationOutcome::Agreed {
+            delay_minutes: 240,
+            cancelled: false
+        }
+    );
+}
+
+#[test]
+fn fallback_to_settled_outcome_keeps_resolution() {
+    // 확정 결과로 되돌아가는 분쟁은 관측 라운드를 다시 열지 않는다.
+    assert!(!reopens_observations(FlightPolicyStatus::Claimable as u8));
+    assert!(!reopens_observations(FlightPolicyStatus::NoClaim as u8));
+    assert!(reopens_observations(FlightPolicyStatus::Issued as u8));
+}
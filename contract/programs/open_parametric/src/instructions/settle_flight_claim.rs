@@ -1,21 +1,45 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
+use crate::constants::XOL_EVENT_SPACE;
 use crate::errors::OpenParamError;
-use crate::math::{split_by_bps, BPS_DENOM};
+use crate::math::{split_by_bps, stop_loss_recovery, xol_event_recovery, BPS_DENOM};
 use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::create_flight_policies_batch::create_pda_account;
+use super::create_flight_policy_from_master::release_flight;
+use super::file_flight_dispute::is_challenge_period_over;
+use super::manage_master_roles::has_master_role;
+use super::settle_flight_no_claim::record_cession;
 
 #[derive(Accounts)]
 pub struct SettleFlightClaim<'info> {
+    #[account(mut)]
     pub executor: Signer<'info>,
+    #[account(mut)]
     pub master_policy: Account<'info, MasterPolicy>,
     #[account(mut)]
     pub flight_policy: Account<'info, FlightPolicy>,
     #[account(mut)]
-    pub leader_deposit_token: Account<'info, TokenAccount>,
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub reinsurer_pool_token: InterfaceAccount<'info, TokenAccount>,
+    /// XoL 레이어 부담분이 있는 경우 필요
+    #[account(mut)]
+    pub xol_pool_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: XoL 레이어 사용 시 필요한 사고별 누적 손해 PDA. seeds/owner는 handler에서 검증하고 없으면 생성한다.
+    #[account(mut)]
+    pub xol_event: Option<UncheckedAccount<'info>>,
+    /// stop-loss 회수분이 있는 경우 필요
+    #[account(mut)]
+    pub stop_loss_pool_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// FlightPolicy에 수익자 계정이 지정된 경우 필요
     #[account(mut)]
-    pub reinsurer_pool_token: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub beneficiary_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightClaim<'a>>) -> Result<()> {
@@ -23,13 +47,20 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightClaim<'a>>) -> Resul
     let flight = &mut ctx.accounts.flight_policy;
 
     // Claimable 상태의 child 정책만 청구 정산할 수 있다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
     require!(
         master.status == MasterPolicyStatus::Active as u8,
         OpenParamError::MasterNotActive
     );
     require!(
-        ctx.accounts.executor.key() == master.leader
-            || ctx.accounts.executor.key() == master.operator,
+        has_master_role(master, ctx.accounts.executor.key(), MasterRole::Settler),
         OpenParamError::Unauthorized
     );
     require!(flight.master == master.key(), OpenParamError::InvalidInput);
@@ -37,6 +68,10 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightClaim<'a>>) -> Resul
         flight.status == FlightPolicyStatus::Claimable as u8,
         OpenParamError::InvalidState
     );
+    require!(
+        is_challenge_period_over(flight.challenge_deadline, Clock::get()?.unix_timestamp),
+        OpenParamError::ChallengePeriodActive
+    );
     require!(
         ctx.accounts.leader_deposit_token.key() == master.leader_deposit_wallet,
         OpenParamError::InvalidInput
@@ -53,10 +88,18 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightClaim<'a>>) -> Resul
         ctx.accounts.reinsurer_pool_token.mint == master.currency_mint,
         OpenParamError::InvalidInput
     );
+    require!(
+        ctx.accounts.leader_deposit_token.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
     require!(
         ctx.accounts.reinsurer_pool_token.owner == master.key(),
         OpenParamError::InvalidSettlementTarget
     );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
 
     require!(
         ctx.remaining_accounts.len() == master.participants.len(),
@@ -67,39 +110,105 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightClaim<'a>>) -> Resul
     require!(payout > 0, OpenParamError::InvalidPayout);
 
     let insurer_ratios: Vec<u16> = master.participants.iter().map(|p| p.share_bps).collect();
-    // 총 payout을 재보험사 몫 + 보험사(leader/A/B...) 몫으로 분리한다.
-    let (reinsurer_amount, insurer_amounts) =
-        calc_claim_split(payout, master.reinsurer_effective_bps, &insurer_ratios)?;
+    // 이번 항공편 보험료까지 경과 보험료에 넣은 손해율로 stop-loss 회수액을 정한다.
+    let mut experience = LossExperience::of(master);
+    let stop_loss_due = experience.stop_loss_due(
+        flight.premium_paid,
+        payout,
+        master.stop_loss_attachment_bps,
+        master.stop_loss_limit,
+    )?;
+    // XoL은 같은 항공편(편명+출발 시각) 청구를 하나의 사고로 합산해 retention/limit을 적용한다.
+    let xol_amount = if master.xol_limit > 0 {
+        let xol_event = ctx
+            .accounts
+            .xol_event
+            .as_ref()
+            .ok_or(OpenParamError::InvalidAccountList)?;
+        record_xol_event_loss(
+            ctx.program_id,
+            master,
+            flight,
+            &xol_event.to_account_info(),
+            ctx.accounts.executor.to_account_info(),
+            &ctx.accounts.system_program,
+        )?
+    } else {
+        0
+    };
+    // 총 payout을 XoL 몫 + 재보험사 몫 + stop-loss 몫 + 보험사(leader/A/B...) 몫으로 분리한다.
+    let split = calc_claim_split(
+        payout,
+        xol_amount,
+        claim_cession_bps(
+            master.commission_mode,
+            flight.ceded_ratio_bps,
+            flight.reinsurer_effective_bps,
+        ),
+        &insurer_ratios,
+        stop_loss_due,
+    )?;
 
     let seed_master_id = master.master_id.to_le_bytes();
     let seeds = &[
         b"master_policy".as_ref(),
-        master.leader.as_ref(),
+        master.seed_leader.as_ref(),
         seed_master_id.as_ref(),
         &[master.bump],
     ];
     let signer = &[&seeds[..]];
+    let currency_mint = &ctx.accounts.currency_mint;
+
+    // transfer fee가 있는 mint는 리더 deposit에 실제로 모인 금액만 지급/기록한다.
+    let mut collected: u64 = 0;
+    if split.xol_amount > 0 {
+        collected = collected
+            .checked_add(collect_from_layer_pool(
+                master,
+                ctx.accounts.xol_pool_token.as_ref(),
+                master.xol_pool_wallet,
+                &ctx.accounts.token_program,
+                currency_mint,
+                &ctx.accounts.leader_deposit_token,
+                split.xol_amount,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
+    if split.stop_loss_amount > 0 {
+        collected = collected
+            .checked_add(collect_from_layer_pool(
+                master,
+                ctx.accounts.stop_loss_pool_token.as_ref(),
+                master.stop_loss_pool_wallet,
+                &ctx.accounts.token_program,
+                currency_mint,
+                &ctx.accounts.leader_deposit_token,
+                split.stop_loss_amount,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
 
-    if reinsurer_amount > 0 {
+    if split.reinsurer_amount > 0 {
         // 재보험 풀에서 리더 deposit으로 재보험사 부담분을 이동한다.
-        let reins_transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.reinsurer_pool_token.to_account_info(),
-                to: ctx.accounts.leader_deposit_token.to_account_info(),
-                authority: ctx.accounts.master_policy.to_account_info(),
-            },
-            signer,
-        );
-        token::transfer(reins_transfer_ctx, reinsurer_amount)?;
+        collected = collected
+            .checked_add(transfer_checked_received(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.reinsurer_pool_token.to_account_info(),
+                currency_mint,
+                ctx.accounts.leader_deposit_token.to_account_info(),
+                ctx.accounts.master_policy.to_account_info(),
+                signer,
+                split.reinsurer_amount,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
     }
 
-    for (i, amount) in insurer_amounts.iter().enumerate() {
+    for (i, amount) in split.insurer_amounts.iter().enumerate() {
         if *amount == 0 {
             continue;
         }
         let pool_info = &ctx.remaining_accounts[i];
-        let pool_wallet: Account<TokenAccount> = Account::try_from(pool_info)?;
+        let pool_wallet: InterfaceAccount<TokenAccount> = InterfaceAccount::try_from(pool_info)?;
 
         require!(
             pool_wallet.key() == master.participants[i].pool_wallet,
@@ -115,36 +224,398 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightClaim<'a>>) -> Resul
         );
 
         // 각 참여사 풀 지갑에서 리더 deposit으로 해당 부담분을 이체한다.
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: pool_info.to_account_info(),
-                to: ctx.accounts.leader_deposit_token.to_account_info(),
-                authority: ctx.accounts.master_policy.to_account_info(),
-            },
-            signer,
-        );
-        token::transfer(transfer_ctx, *amount)?;
+        collected = collected
+            .checked_add(transfer_checked_received(
+                ctx.accounts.token_program.to_account_info(),
+                pool_info.to_account_info(),
+                currency_mint,
+                ctx.accounts.leader_deposit_token.to_account_info(),
+                ctx.accounts.master_policy.to_account_info(),
+                signer,
+                *amount,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
+
+    // 수익자 지정 건은 수익자 계정에 실제 입금된 금액을 지급 보험금으로 기록한다.
+    let mut deliveries = Vec::new();
+    if flight.beneficiary_token != Pubkey::default() {
+        let beneficiary_token = ctx
+            .accounts
+            .beneficiary_token
+            .as_ref()
+            .ok_or(OpenParamError::InvalidAccountList)?;
+        let delivered = pay_beneficiary(
+            master,
+            flight.beneficiary_token,
+            collected,
+            &ctx.accounts.token_program,
+            currency_mint,
+            &ctx.accounts.leader_deposit_token,
+            beneficiary_token,
+        )?;
+        deliveries.push((collected, delivered));
     }
 
     flight.status = FlightPolicyStatus::Paid as u8;
     flight.updated_at = Clock::get()?.unix_timestamp;
+
+    // 정산 완료 건은 미정산 집계에서 빼고 누적 손해 실적에 더한다.
+    let master = &mut ctx.accounts.master_policy;
+    release_flight(master, &ctx.accounts.flight_policy)?;
+    experience.record(
+        ctx.accounts.flight_policy.premium_paid,
+        payout,
+        split.stop_loss_amount,
+    )?;
+    record_loss_experience(
+        master,
+        &experience,
+        recorded_claims_paid(collected, &deliveries)?,
+    )?;
+    record_cession(master, 0, 0, split.reinsurer_amount)?;
     Ok(())
 }
 
-pub(crate) fn calc_claim_split(
+pub(crate) fn pay_beneficiary<'info>(
+    master: &Account<'info, MasterPolicy>,
+    beneficiary: Pubkey,
     payout: u64,
+    token_program: &Interface<'info, TokenInterface>,
+    currency_mint: &InterfaceAccount<'info, Mint>,
+    leader_deposit_token: &InterfaceAccount<'info, TokenAccount>,
+    beneficiary_token: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<u64> {
+    require!(
+        beneficiary_token.key() == beneficiary,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        beneficiary_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+
+    let seed_master_id = master.master_id.to_le_bytes();
+    let seeds = &[
+        b"master_policy".as_ref(),
+        master.seed_leader.as_ref(),
+        seed_master_id.as_ref(),
+        &[master.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // 리더 deposit에 모인 보험금 전액을 수익자 계정으로 지급하고 실제 입금액을 돌려준다.
+    transfer_checked_received(
+        token_program.to_account_info(),
+        leader_deposit_token.to_account_info(),
+        currency_mint,
+        beneficiary_token.to_account_info(),
+        master.to_account_info(),
+        signer,
+        payout,
+    )
+}
+
+pub(crate) fn claim_cession_bps(
+    commission_mode: u8,
+    ceded_ratio_bps: u16,
     reinsurer_effective_bps: u16,
+) -> u16 {
+    // Explicit 모드는 출재율 그대로, Legacy 모드는 수수료 차감 실효 지분율로 보험금을 출재한다.
+    if commission_mode == CommissionMode::Explicit as u8 {
+        ceded_ratio_bps
+    } else {
+        reinsurer_effective_bps
+    }
+}
+
+pub(crate) struct ClaimSplit {
+    pub xol_amount: u64,
+    pub reinsurer_amount: u64,
+    pub stop_loss_amount: u64,
+    pub insurer_amounts: Vec<u64>,
+}
+
+pub(crate) fn calc_claim_split(
+    payout: u64,
+    xol_amount: u64,
+    reinsurer_cession_bps: u16,
     insurer_share_bps: &[u16],
-) -> std::result::Result<(u64, Vec<u64>), OpenParamError> {
-    // payout을 재보험사 실효 지분율 기준으로 분할하고, 잔여는 참여사 비율로 재분배한다.
-    let reinsurer_amount = payout
-        .checked_mul(reinsurer_effective_bps as u64)
+    stop_loss_due: u64,
+) -> std::result::Result<ClaimSplit, OpenParamError> {
+    // 1) 사고 단위 누적 손해로 산정한 XoL 회수액을 먼저 뺀다.
+    // 2) 마스터 전체 손해율(발생 손해 / 경과 보험료) 기준 stop-loss 회수액을 이어서 뺀다.
+    // 3) 나머지를 재보험 출재 비율(quota share)로 분할하고, 잔여는 참여사 비율로 나눈다.
+    let net_payout = payout
+        .checked_sub(xol_amount)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let stop_loss_amount = stop_loss_due.min(net_payout);
+    let shared = net_payout - stop_loss_amount;
+    let reinsurer_amount = shared
+        .checked_mul(reinsurer_cession_bps as u64)
         .ok_or(OpenParamError::MathOverflow)?
         / BPS_DENOM;
-    let insurer_total = payout
+    let retained = shared
         .checked_sub(reinsurer_amount)
         .ok_or(OpenParamError::MathOverflow)?;
-    let insurer_amounts = split_by_bps(insurer_total, insurer_share_bps)?;
-    Ok((reinsurer_amount, insurer_amounts))
+    let insurer_amounts = split_by_bps(retained, insurer_share_bps)?;
+    Ok(ClaimSplit {
+        xol_amount,
+        reinsurer_amount,
+        stop_loss_amount,
+        insurer_amounts,
+    })
+}
+
+pub(crate) fn record_xol_event_loss<'info>(
+    program_id: &Pubkey,
+    master: &Account<'info, MasterPolicy>,
+    flight: &FlightPolicy,
+    event_info: &AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<u64> {
+    let master_key = master.key();
+    let departure_bytes = flight.departure_ts.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[
+            b"xol_event",
+            master_key.as_ref(),
+            flight.flight_no.as_bytes(),
+            &departure_bytes,
+        ],
+        program_id,
+    );
+    require!(
+        event_info.key() == expected,
+        OpenParamError::InvalidAccountList
+    );
+
+    // 사고의 첫 청구면 누적 손해 계정을 만들고, 이후에는 기존 누적값에 이어서 적용한다.
+    let mut event = if event_info.data_is_empty() {
+        let seeds = &[
+            b"xol_event".as_ref(),
+            master_key.as_ref(),
+            flight.flight_no.as_bytes(),
+            departure_bytes.as_ref(),
+            &[bump],
+        ];
+        create_pda_account(
+            system_program,
+            payer,
+            event_info.clone(),
+            &[&seeds[..]],
+            XOL_EVENT_SPACE,
+            program_id,
+        )?;
+        XolEventLoss {
+            master: master_key,
+            flight_no: flight.flight_no.clone(),
+            departure_ts: flight.departure_ts,
+            gross_loss: 0,
+            recovered: 0,
+            bump,
+        }
+    } else {
+        require!(
+            event_info.owner == program_id,
+            OpenParamError::InvalidAccountList
+        );
+        let data = event_info.try_borrow_data()?;
+        XolEventLoss::try_deserialize(&mut &data[..])?
+    };
+
+    let recovery = xol_event_recovery(
+        event.gross_loss,
+        event.recovered,
+        flight.payout_amount,
+        master.xol_retention,
+        master.xol_limit,
+    )?;
+    event.gross_loss = event
+        .gross_loss
+        .checked_add(flight.payout_amount)
+        .ok_or(OpenParamError::MathOverflow)?;
+    event.recovered = event
+        .recovered
+        .checked_add(recovery)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let mut data = event_info.try_borrow_mut_data()?;
+    let mut writer: &mut [u8] = &mut data;
+    event.try_serialize(&mut writer)?;
+    Ok(recovery)
+}
+
+pub(crate) fn collect_from_layer_pool<'info>(
+    master: &Account<'info, MasterPolicy>,
+    pool_token: Option<&InterfaceAccount<'info, TokenAccount>>,
+    expected_pool: Pubkey,
+    token_program: &Interface<'info, TokenInterface>,
+    currency_mint: &InterfaceAccount<'info, Mint>,
+    leader_deposit_token: &InterfaceAccount<'info, TokenAccount>,
+    amount: u64,
+) -> Result<u64> {
+    let pool_token = pool_token.ok_or(OpenParamError::InvalidAccountList)?;
+    require!(
+        pool_token.key() == expected_pool,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        pool_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        pool_token.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
+
+    let seed_master_id = master.master_id.to_le_bytes();
+    let seeds = &[
+        b"master_policy".as_ref(),
+        master.seed_leader.as_ref(),
+        seed_master_id.as_ref(),
+        &[master.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // XoL/stop-loss 풀에서 리더 deposit으로 해당 레이어 부담분을 이동한다.
+    transfer_checked_received(
+        token_program.to_account_info(),
+        pool_token.to_account_info(),
+        currency_mint,
+        leader_deposit_token.to_account_info(),
+        master.to_account_info(),
+        signer,
+        amount,
+    )
+}
+
+pub(crate) fn allocate_received(
+    amounts: &[u64],
+    received_total: u64,
+) -> std::result::Result<Vec<u64>, OpenParamError> {
+    // 합산 이체의 실제 입금액을 항목별 금액 비율로 나눈다(내림, 나머지는 앞 항목부터 1씩).
+    let total = amounts
+        .iter()
+        .try_fold(0u64, |acc, a| acc.checked_add(*a))
+        .ok_or(OpenParamError::MathOverflow)?;
+    if received_total > total {
+        return Err(OpenParamError::InvalidAmount);
+    }
+    if total == 0 {
+        return Ok(vec![0; amounts.len()]);
+    }
+    let mut shares: Vec<u64> = amounts
+        .iter()
+        .map(|a| (*a as u128 * received_total as u128 / total as u128) as u64)
+        .collect();
+    let mut remainder = received_total - shares.iter().sum::<u64>();
+    for (share, amount) in shares.iter_mut().zip(amounts) {
+        if remainder == 0 {
+            break;
+        }
+        if *amount > 0 {
+            *share += 1;
+            remainder -= 1;
+        }
+    }
+    Ok(shares)
+}
+
+pub(crate) fn recorded_claims_paid(
+    collected: u64,
+    deliveries: &[(u64, u64)],
+) -> std::result::Result<u64, OpenParamError> {
+    // 리더 deposit에 모인 금액 중 수익자에게 보낸 몫은 (보낸 금액, 실제 입금액) 쌍의 입금액으로 바꿔 기록한다.
+    deliveries
+        .iter()
+        .try_fold(collected, |acc, (sent, delivered)| {
+            acc.checked_sub(*sent)?.checked_add(*delivered)
+        })
+        .ok_or(OpenParamError::MathOverflow)
+}
+
+// stop-loss 손해율 산정 상태. 단건/배치 정산이 항공편마다 같은 순서로 갱신해 같은 결과를 낸다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct LossExperience {
+    pub earned_premium: u64,
+    pub incurred_claims: u64,
+    pub stop_loss_recovered: u64,
+}
+
+impl LossExperience {
+    pub(crate) fn of(master: &MasterPolicy) -> Self {
+        Self {
+            earned_premium: master.earned_premium,
+            incurred_claims: master.incurred_claims,
+            stop_loss_recovered: master.stop_loss_recovered,
+        }
+    }
+
+    pub(crate) fn stop_loss_due(
+        &self,
+        premium_earned: u64,
+        payout: u64,
+        attachment_bps: u16,
+        limit: u64,
+    ) -> std::result::Result<u64, OpenParamError> {
+        // 정산하는 항공편의 보험료도 경과 보험료로 보고 손해율 분모에 넣는다.
+        let earned = self
+            .earned_premium
+            .checked_add(premium_earned)
+            .ok_or(OpenParamError::MathOverflow)?;
+        stop_loss_recovery(
+            self.incurred_claims,
+            payout,
+            earned,
+            attachment_bps,
+            limit,
+            self.stop_loss_recovered,
+        )
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        premium_earned: u64,
+        incurred: u64,
+        stop_loss_recovered: u64,
+    ) -> std::result::Result<(), OpenParamError> {
+        // 발생 손해는 transfer fee와 무관한 청구 금액(payout) 기준으로 누적한다.
+        self.earned_premium = self
+            .earned_premium
+            .checked_add(premium_earned)
+            .ok_or(OpenParamError::MathOverflow)?;
+        self.incurred_claims = self
+            .incurred_claims
+            .checked_add(incurred)
+            .ok_or(OpenParamError::MathOverflow)?;
+        self.stop_loss_recovered = self
+            .stop_loss_recovered
+            .checked_add(stop_loss_recovered)
+            .ok_or(OpenParamError::MathOverflow)?;
+        Ok(())
+    }
+}
+
+pub(crate) fn record_loss_experience(
+    master: &mut MasterPolicy,
+    experience: &LossExperience,
+    claims_paid: u64,
+) -> Result<()> {
+    // stop-loss 산정 상태를 반영하고, 실제 지급액(수취 기준)은 별도로 누적한다.
+    master.earned_premium = experience.earned_premium;
+    master.incurred_claims = experience.incurred_claims;
+    master.stop_loss_recovered = experience.stop_loss_recovered;
+    master.total_claims_paid = master
+        .total_claims_paid
+        .checked_add(claims_paid)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(())
+}
+
+pub(crate) fn record_earned_premium(master: &mut MasterPolicy, premium_earned: u64) -> Result<()> {
+    // 청구 없이 끝난 항공편은 보험료만 경과 보험료에 더한다.
+    let mut experience = LossExperience::of(master);
+    experience.record(premium_earned, 0, 0)?;
+    record_loss_experience(master, &experience, 0)
 }
@@ -1,10 +1,118 @@
-use super::settle_flight_claim::calc_claim_split;
+use crate::errors::OpenParamError;
+use crate::math::stop_loss_recovery;
+
+use super::settle_flight_claim::{allocate_received, calc_claim_split, recorded_claims_paid};
 
 #[test]
 fn claim_split_matches_example_with_commission() {
     // payout 80 USDC, 재보험 실효지분 45%, 참여사 50/30/20 분배 시 기대값 검증.
     let payout = 80_000_000u64; // 80 USDC with 6 decimals
-    let (reinsurer, insurers) = calc_claim_split(payout, 4_500, &[5_000, 3_000, 2_000]).unwrap();
-    assert_eq!(reinsurer, 36_000_000);
-    assert_eq!(insurers, vec![22_000_000, 13_200_000, 8_800_000]);
+    let split = calc_claim_split(payout, 0, 4_500, &[5_000, 3_000, 2_000], 0).unwrap();
+    assert_eq!(split.xol_amount, 0);
+    assert_eq!(split.reinsurer_amount, 36_000_000);
+    assert_eq!(
+        split.insurer_amounts,
+        vec![22_000_000, 13_200_000, 8_800_000]
+    );
+}
+
+#[test]
+fn xol_layer_applies_before_quota_share() {
+    // 사고 누적 기준 XoL 회수액 20 USDC를 먼저 빼고, 남은 60을 45% quota share로 나눈다.
+    let payout = 80_000_000u64;
+    let split = calc_claim_split(payout, 20_000_000, 4_500, &[5_000, 3_000, 2_000], 0).unwrap();
+    assert_eq!(split.xol_amount, 20_000_000);
+    assert_eq!(split.reinsurer_amount, 27_000_000);
+    assert_eq!(
+        split.insurer_amounts,
+        vec![16_500_000, 9_900_000, 6_600_000]
+    );
+    assert_eq!(
+        split.xol_amount + split.reinsurer_amount + split.insurer_amounts.iter().sum::<u64>(),
+        payout
+    );
+}
+
+#[test]
+fn stop_loss_is_recovered_before_quota_share() {
+    // stop-loss 회수액 24 USDC를 먼저 빼고 남은 56 USDC를 45% quota share와 참여사 비율로 나눈다.
+    let split = calc_claim_split(80_000_000, 0, 4_500, &[5_000, 3_000, 2_000], 24_000_000).unwrap();
+    assert_eq!(split.stop_loss_amount, 24_000_000);
+    assert_eq!(split.reinsurer_amount, 25_200_000);
+    assert_eq!(
+        split.insurer_amounts,
+        vec![15_400_000, 9_240_000, 6_160_000]
+    );
+
+    // 회수 예정액이 청구 금액보다 크면 청구 금액까지만 부담한다.
+    let split = calc_claim_split(80_000_000, 0, 4_500, &[5_000, 3_000, 2_000], 90_000_000).unwrap();
+    assert_eq!(split.stop_loss_amount, 80_000_000);
+    assert_eq!(split.reinsurer_amount, 0);
+    assert_eq!(split.insurer_amounts, vec![0, 0, 0]);
+}
+
+#[test]
+fn stop_loss_recovery_does_not_depend_on_settlement_order() {
+    // 경과 보험료 100 USDC, attachment 50%, 청구 80/40 USDC를 어느 순서로 정산해도 총 회수액은 70 USDC다.
+    let settle = |payouts: &[u64]| {
+        let mut claims_paid = 0u64;
+        let mut recovered = 0u64;
+        for payout in payouts {
+            let due = stop_loss_recovery(
+                claims_paid,
+                *payout,
+                100_000_000,
+                5_000,
+                1_000_000_000,
+                recovered,
+            )
+            .unwrap();
+            let split = calc_claim_split(*payout, 0, 4_500, &[5_000, 3_000, 2_000], due).unwrap();
+            claims_paid += payout;
+            recovered += split.stop_loss_amount;
+        }
+        recovered
+    };
+    assert_eq!(settle(&[80_000_000, 40_000_000]), 70_000_000);
+    assert_eq!(settle(&[40_000_000, 80_000_000]), 70_000_000);
+}
+
+#[test]
+fn explicit_mode_cedes_claims_at_full_ceded_ratio() {
+    // 출재율 50%를 그대로 적용하면 80 USDC 중 40 USDC가 출재 보험금이다.
+    let split = calc_claim_split(80_000_000, 0, 5_000, &[5_000, 3_000, 2_000], 0).unwrap();
+    assert_eq!(split.reinsurer_amount, 40_000_000);
+    assert_eq!(
+        split.insurer_amounts,
+        vec![20_000_000, 12_000_000, 8_000_000]
+    );
+}
+
+#[test]
+fn received_claim_collection_is_allocated_pro_rata() {
+    // transfer fee로 덜 모인 청구 금액은 항공편별 보험금 비율로 나누고 합계를 보존한다.
+    let shares = allocate_received(&[80_000_000, 20_000_000, 0], 99_000_001).unwrap();
+    assert_eq!(shares, vec![79_200_001, 19_800_000, 0]);
+    assert_eq!(
+        allocate_received(&[50_000_000], 50_000_000).unwrap(),
+        vec![50_000_000]
+    );
+    assert!(matches!(
+        allocate_received(&[10], 11),
+        Err(OpenParamError::InvalidAmount)
+    ));
+}
+
+#[test]
+fn claims_paid_records_amount_delivered_to_beneficiary() {
+    // 100 USDC가 모였고 그중 수익자 몫 80 USDC가 transfer fee로 79.2 USDC만 입금되면 99.2 USDC를 기록한다.
+    assert_eq!(
+        recorded_claims_paid(100_000_000, &[(80_000_000, 79_200_000)]).unwrap(),
+        99_200_000
+    );
+    assert_eq!(recorded_claims_paid(100_000_000, &[]).unwrap(), 100_000_000);
+    assert!(matches!(
+        recorded_claims_paid(10, &[(11, 11)]),
+        Err(OpenParamError::MathOverflow)
+    ));
 }
@@ -1,21 +1,37 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::errors::OpenParamError;
 use crate::math::{split_by_bps, BPS_DENOM};
 use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::create_flight_policy_from_master::{
+    calc_premium_deductions, pay_treasury, record_premium_deductions, release_flight,
+};
+use super::file_flight_dispute::is_challenge_period_over;
+use super::manage_master_roles::has_master_role;
+use super::settle_flight_claim::record_earned_premium;
 
 #[derive(Accounts)]
 pub struct SettleFlightNoClaim<'info> {
     pub executor: Signer<'info>,
+    #[account(mut)]
     pub master_policy: Account<'info, MasterPolicy>,
     #[account(mut)]
     pub flight_policy: Account<'info, FlightPolicy>,
     #[account(mut)]
-    pub leader_deposit_token: Account<'info, TokenAccount>,
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub reinsurer_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    /// 프로토콜 수수료 차감 시 필요
     #[account(mut)]
-    pub reinsurer_deposit_token: Account<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금 차감 시 필요
+    #[account(mut)]
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightNoClaim<'a>>) -> Result<()> {
@@ -23,13 +39,20 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightNoClaim<'a>>) -> Res
     let flight = &mut ctx.accounts.flight_policy;
 
     // NoClaim 상태의 child 정책만 프리미엄 정산을 수행한다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
     require!(
         master.status == MasterPolicyStatus::Active as u8,
         OpenParamError::MasterNotActive
     );
     require!(
-        ctx.accounts.executor.key() == master.leader
-            || ctx.accounts.executor.key() == master.operator,
+        has_master_role(master, ctx.accounts.executor.key(), MasterRole::Settler),
         OpenParamError::Unauthorized
     );
     require!(flight.master == master.key(), OpenParamError::InvalidInput);
@@ -38,45 +61,167 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightNoClaim<'a>>) -> Res
         OpenParamError::InvalidState
     );
     require!(!flight.premium_distributed, OpenParamError::AlreadySettled);
+    require!(
+        is_challenge_period_over(flight.challenge_deadline, Clock::get()?.unix_timestamp),
+        OpenParamError::ChallengePeriodActive
+    );
+
+    let (protocol_fee, premium_tax) = collect_premium_deductions(
+        master,
+        flight,
+        &ctx.accounts.token_program,
+        &ctx.accounts.currency_mint,
+        &ctx.accounts.leader_deposit_token,
+        ctx.accounts.fee_treasury_token.as_ref(),
+        ctx.accounts.tax_treasury_token.as_ref(),
+    )?;
+    let (ceded_premium, ceding_commission) = distribute_premium(
+        master,
+        flight,
+        &ctx.accounts.token_program,
+        &ctx.accounts.currency_mint,
+        &ctx.accounts.leader_deposit_token,
+        &ctx.accounts.reinsurer_deposit_token,
+        ctx.remaining_accounts,
+    )?;
+
+    flight.premium_distributed = true;
+    flight.status = FlightPolicyStatus::Expired as u8;
+    flight.updated_at = Clock::get()?.unix_timestamp;
+
+    let master = &mut ctx.accounts.master_policy;
+    release_flight(master, &ctx.accounts.flight_policy)?;
+    record_earned_premium(master, ctx.accounts.flight_policy.premium_paid)?;
+    record_premium_deductions(master, protocol_fee, premium_tax)?;
+    record_cession(master, ceded_premium, ceding_commission, 0)?;
 
+    Ok(())
+}
+
+pub(crate) fn settlement_deductions(
+    master: &MasterPolicy,
+    premium: u64,
+) -> std::result::Result<(u64, u64), OpenParamError> {
+    if master.deduction_timing != DeductionTiming::AtNoClaimSettlement as u8 {
+        return Ok((0, 0));
+    }
+    calc_premium_deductions(premium, master.protocol_fee_bps, master.premium_tax_bps)
+}
+
+pub(crate) fn distributable_premium(
+    flight: &FlightPolicy,
+) -> std::result::Result<u64, OpenParamError> {
+    // 리더 deposit에 남아 있는 보험료 = 납입 보험료 - 이미 treasury로 보낸 수수료/세금.
+    flight
+        .premium_paid
+        .checked_sub(flight.protocol_fee_paid)
+        .and_then(|v| v.checked_sub(flight.premium_tax_paid))
+        .ok_or(OpenParamError::MathOverflow)
+}
+
+pub(crate) fn collect_premium_deductions<'a>(
+    master: &Account<'a, MasterPolicy>,
+    flight: &mut FlightPolicy,
+    token_program: &Interface<'a, TokenInterface>,
+    currency_mint: &InterfaceAccount<'a, Mint>,
+    leader_deposit_token: &InterfaceAccount<'a, TokenAccount>,
+    fee_treasury_token: Option<&InterfaceAccount<'a, TokenAccount>>,
+    tax_treasury_token: Option<&InterfaceAccount<'a, TokenAccount>>,
+) -> Result<(u64, u64)> {
+    // 무청구 정산 시점 차감 설정이면 리더 deposit에서 수수료/세금을 treasury로 보내고 기록한다.
+    let (protocol_fee, premium_tax) = settlement_deductions(master, flight.premium_paid)?;
+    if protocol_fee == 0 && premium_tax == 0 {
+        return Ok((0, 0));
+    }
+    let seed_master_id = master.master_id.to_le_bytes();
+    let seeds = &[
+        b"master_policy".as_ref(),
+        master.seed_leader.as_ref(),
+        seed_master_id.as_ref(),
+        &[master.bump],
+    ];
+    let signer = &[&seeds[..]];
+    pay_treasury(
+        fee_treasury_token,
+        master.fee_treasury_token,
+        token_program.to_account_info(),
+        currency_mint,
+        leader_deposit_token.to_account_info(),
+        master.to_account_info(),
+        signer,
+        protocol_fee,
+    )?;
+    pay_treasury(
+        tax_treasury_token,
+        master.tax_treasury_token,
+        token_program.to_account_info(),
+        currency_mint,
+        leader_deposit_token.to_account_info(),
+        master.to_account_info(),
+        signer,
+        premium_tax,
+    )?;
+    flight.protocol_fee_paid = protocol_fee;
+    flight.premium_tax_paid = premium_tax;
+    Ok((protocol_fee, premium_tax))
+}
+
+pub(crate) fn distribute_premium<'a>(
+    master: &Account<'a, MasterPolicy>,
+    flight: &FlightPolicy,
+    token_program: &Interface<'a, TokenInterface>,
+    currency_mint: &InterfaceAccount<'a, Mint>,
+    leader_deposit_token: &InterfaceAccount<'a, TokenAccount>,
+    reinsurer_deposit_token: &InterfaceAccount<'a, TokenAccount>,
+    deposit_accounts: &'a [AccountInfo<'a>],
+) -> Result<(u64, u64)> {
     require!(
-        ctx.accounts.leader_deposit_token.key() == master.leader_deposit_wallet,
+        currency_mint.key() == master.currency_mint,
         OpenParamError::InvalidInput
     );
     require!(
-        ctx.accounts.reinsurer_deposit_token.key() == master.reinsurer_deposit_wallet,
+        leader_deposit_token.key() == master.leader_deposit_wallet,
         OpenParamError::InvalidInput
     );
     require!(
-        ctx.accounts.leader_deposit_token.mint == master.currency_mint,
+        reinsurer_deposit_token.key() == master.reinsurer_deposit_wallet,
         OpenParamError::InvalidInput
     );
     require!(
-        ctx.accounts.reinsurer_deposit_token.mint == master.currency_mint,
+        leader_deposit_token.mint == master.currency_mint,
         OpenParamError::InvalidInput
     );
     require!(
-        ctx.accounts.leader_deposit_token.owner == master.key(),
+        reinsurer_deposit_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        leader_deposit_token.owner == master.key(),
         OpenParamError::InvalidSettlementTarget
     );
 
     require!(
-        ctx.remaining_accounts.len() == master.participants.len(),
+        deposit_accounts.len() == master.participants.len(),
         OpenParamError::InvalidAccountList
     );
 
     let insurer_ratios: Vec<u16> = master.participants.iter().map(|p| p.share_bps).collect();
-    // premium을 재보험사 몫 + 보험사(leader/A/B...) 몫으로 분리한다.
-    let (reinsurer_amount, insurer_amounts) = calc_no_claim_split(
-        flight.premium_paid,
-        master.reinsurer_effective_bps,
-        &insurer_ratios,
-    )?;
+    // premium을 출재 보험료(수수료 차감 후 재보험사 몫) + 보험사(leader/A/B...) 몫 + 출재 수수료로 분리한다.
+    // 출재 조건은 항공편 발급 시점 약관 스냅샷을 따른다.
+    let (ceded_ratio_bps, commission_bps) = premium_cession_terms(
+        master.commission_mode,
+        flight.ceded_ratio_bps,
+        flight.reins_commission_bps,
+        flight.reinsurer_effective_bps,
+    );
+    let premium = distributable_premium(flight)?;
+    let split = calc_no_claim_split(premium, ceded_ratio_bps, commission_bps, &insurer_ratios)?;
+    let reinsurer_amount = split.reinsurer_net();
 
     let seed_master_id = master.master_id.to_le_bytes();
     let seeds = &[
         b"master_policy".as_ref(),
-        master.leader.as_ref(),
+        master.seed_leader.as_ref(),
         seed_master_id.as_ref(),
         &[master.bump],
     ];
@@ -84,24 +229,25 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightNoClaim<'a>>) -> Res
 
     if reinsurer_amount > 0 {
         // 리더 deposit에 모인 premium 중 재보험사 몫을 재보험사 deposit으로 보낸다.
-        let reins_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.leader_deposit_token.to_account_info(),
-                to: ctx.accounts.reinsurer_deposit_token.to_account_info(),
-                authority: ctx.accounts.master_policy.to_account_info(),
-            },
+        transfer_checked_received(
+            token_program.to_account_info(),
+            leader_deposit_token.to_account_info(),
+            currency_mint,
+            reinsurer_deposit_token.to_account_info(),
+            master.to_account_info(),
             signer,
-        );
-        token::transfer(reins_ctx, reinsurer_amount)?;
+            reinsurer_amount,
+        )?;
     }
 
-    for (i, amount) in insurer_amounts.iter().enumerate() {
-        if *amount == 0 {
+    // 보유 보험료와 출재 수수료 몫은 같은 참여사 deposit으로 가므로 합산해 한 번만 이체한다.
+    for (i, amount) in split.insurer_payouts()?.into_iter().enumerate() {
+        if amount == 0 {
             continue;
         }
-        let deposit_info = &ctx.remaining_accounts[i];
-        let deposit_wallet: Account<TokenAccount> = Account::try_from(deposit_info)?;
+        let deposit_info = &deposit_accounts[i];
+        let deposit_wallet: InterfaceAccount<TokenAccount> =
+            InterfaceAccount::try_from(deposit_info)?;
 
         require!(
             deposit_wallet.key() == master.participants[i].deposit_wallet,
@@ -112,39 +258,107 @@ pub fn handler<'a>(ctx: Context<'_, '_, 'a, 'a, SettleFlightNoClaim<'a>>) -> Res
             OpenParamError::InvalidInput
         );
 
-        // 남은 premium은 참여사 deposit 지갑으로 비율대로 분배한다.
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.leader_deposit_token.to_account_info(),
-                to: deposit_info.to_account_info(),
-                authority: ctx.accounts.master_policy.to_account_info(),
-            },
+        transfer_checked_received(
+            token_program.to_account_info(),
+            leader_deposit_token.to_account_info(),
+            currency_mint,
+            deposit_info.to_account_info(),
+            master.to_account_info(),
             signer,
-        );
-        token::transfer(transfer_ctx, *amount)?;
+            amount,
+        )?;
     }
 
-    flight.premium_distributed = true;
-    flight.status = FlightPolicyStatus::Expired as u8;
-    flight.updated_at = Clock::get()?.unix_timestamp;
+    Ok((split.ceded_premium, split.ceding_commission))
+}
 
-    Ok(())
+pub(crate) fn premium_cession_terms(
+    commission_mode: u8,
+    ceded_ratio_bps: u16,
+    reins_commission_bps: u16,
+    reinsurer_effective_bps: u16,
+) -> (u16, u16) {
+    // (출재율, 출재 수수료율). Legacy 모드는 수수료를 실효 지분율에 녹여 별도 수수료가 없다.
+    if commission_mode == CommissionMode::Explicit as u8 {
+        (ceded_ratio_bps, reins_commission_bps)
+    } else {
+        (reinsurer_effective_bps, 0)
+    }
+}
+
+pub(crate) struct NoClaimSplit {
+    pub ceded_premium: u64,
+    pub ceding_commission: u64,
+    pub insurer_amounts: Vec<u64>,
+    pub commission_amounts: Vec<u64>,
+}
+
+impl NoClaimSplit {
+    // 재보험사 deposit으로 실제 이체되는 금액(출재 보험료 - 출재 수수료).
+    pub fn reinsurer_net(&self) -> u64 {
+        self.ceded_premium - self.ceding_commission
+    }
+
+    // 참여사 deposit으로 실제 이체되는 금액(보유 보험료 + 출재 수수료 몫).
+    pub fn insurer_payouts(&self) -> std::result::Result<Vec<u64>, OpenParamError> {
+        self.insurer_amounts
+            .iter()
+            .zip(&self.commission_amounts)
+            .map(|(amount, commission)| {
+                amount
+                    .checked_add(*commission)
+                    .ok_or(OpenParamError::MathOverflow)
+            })
+            .collect()
+    }
 }
 
 pub(crate) fn calc_no_claim_split(
     premium: u64,
-    reinsurer_effective_bps: u16,
+    ceded_ratio_bps: u16,
+    commission_bps: u16,
     insurer_share_bps: &[u16],
-) -> std::result::Result<(u64, Vec<u64>), OpenParamError> {
-    // premium을 재보험 실효 지분과 보험사 지분으로 분할한다.
-    let reinsurer_amount = premium
-        .checked_mul(reinsurer_effective_bps as u64)
+) -> std::result::Result<NoClaimSplit, OpenParamError> {
+    // premium을 출재 보험료와 보유 보험료로 나누고, 출재 보험료에 대한 수수료를 참여사 비율로 돌려준다.
+    let ceded_premium = premium
+        .checked_mul(ceded_ratio_bps as u64)
+        .ok_or(OpenParamError::MathOverflow)?
+        / BPS_DENOM;
+    let ceding_commission = ceded_premium
+        .checked_mul(commission_bps as u64)
         .ok_or(OpenParamError::MathOverflow)?
         / BPS_DENOM;
     let insurer_total = premium
-        .checked_sub(reinsurer_amount)
+        .checked_sub(ceded_premium)
         .ok_or(OpenParamError::MathOverflow)?;
     let insurer_amounts = split_by_bps(insurer_total, insurer_share_bps)?;
-    Ok((reinsurer_amount, insurer_amounts))
+    let commission_amounts = split_by_bps(ceding_commission, insurer_share_bps)?;
+    Ok(NoClaimSplit {
+        ceded_premium,
+        ceding_commission,
+        insurer_amounts,
+        commission_amounts,
+    })
+}
+
+pub(crate) fn record_cession(
+    master: &mut MasterPolicy,
+    ceded_premium: u64,
+    ceding_commission: u64,
+    ceded_claims: u64,
+) -> Result<()> {
+    // 출재 보험료(수수료 차감 전), 출재 수수료, 재보험사 부담 보험금을 마스터 누적 실적에 더한다.
+    master.total_ceded_premium = master
+        .total_ceded_premium
+        .checked_add(ceded_premium)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.total_ceding_commission = master
+        .total_ceding_commission
+        .checked_add(ceding_commission)
+        .ok_or(OpenParamError::MathOverflow)?;
+    master.total_ceded_claims = master
+        .total_ceded_claims
+        .checked_add(ceded_claims)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(())
 }
@@ -1,11 +1,88 @@
-use super::settle_flight_no_claim::calc_no_claim_split;
+use crate::state::CommissionMode;
+
+use super::settle_flight_claim::{calc_claim_split, claim_cession_bps};
+use super::settle_flight_no_claim::{calc_no_claim_split, premium_cession_terms};
 
 #[test]
 fn no_claim_split_matches_example_with_commission() {
-    // premium 5 USDC를 재보험 45% + 참여사 50/30/20으로 분할한 결과를 검증.
+    // Legacy: premium 5 USDC를 재보험 실효 45% + 참여사 50/30/20으로 분할한 결과를 검증.
     let premium = 5_000_000u64; // 5 USDC with 6 decimals
-    let (reinsurer, insurers) =
-        calc_no_claim_split(premium, 4_500, &[5_000, 3_000, 2_000]).unwrap();
-    assert_eq!(reinsurer, 2_250_000);
-    assert_eq!(insurers, vec![1_375_000, 825_000, 550_000]);
+    let split = calc_no_claim_split(premium, 4_500, 0, &[5_000, 3_000, 2_000]).unwrap();
+    assert_eq!(split.reinsurer_net(), 2_250_000);
+    assert_eq!(split.ceding_commission, 0);
+    assert_eq!(split.insurer_amounts, vec![1_375_000, 825_000, 550_000]);
+}
+
+#[test]
+fn explicit_mode_cedes_full_ratio_and_returns_commission_separately() {
+    // 출재율 50%, 수수료 10%: 출재 보험료 2.5, 수수료 0.25 USDC를 별도로 돌려준다.
+    let premium = 5_000_000u64;
+    let split = calc_no_claim_split(premium, 5_000, 1_000, &[5_000, 3_000, 2_000]).unwrap();
+    assert_eq!(split.ceded_premium, 2_500_000);
+    assert_eq!(split.ceding_commission, 250_000);
+    assert_eq!(split.reinsurer_net(), 2_250_000);
+    assert_eq!(split.insurer_amounts, vec![1_250_000, 750_000, 500_000]);
+    assert_eq!(split.commission_amounts, vec![125_000, 75_000, 50_000]);
+    // 보유 보험료와 수수료 몫은 참여사별로 합산해 한 번에 이체한다.
+    assert_eq!(
+        split.insurer_payouts().unwrap(),
+        vec![1_375_000, 825_000, 550_000]
+    );
+}
+
+#[test]
+fn explicit_and_legacy_modes_differ_in_ceded_claims_and_recorded_cession() {
+    // 출재율 50%, 수수료 10%(실효 45%): 보험료 5 USDC, 보험금 80 USDC.
+    let ratios = [5_000, 3_000, 2_000];
+    let outcome = |mode: CommissionMode| {
+        let (ceded_bps, commission_bps) = premium_cession_terms(mode as u8, 5_000, 1_000, 4_500);
+        let premium = calc_no_claim_split(5_000_000, ceded_bps, commission_bps, &ratios).unwrap();
+        let claim = calc_claim_split(
+            80_000_000,
+            0,
+            claim_cession_bps(mode as u8, 5_000, 4_500),
+            &ratios,
+            0,
+        )
+        .unwrap();
+        (premium, claim)
+    };
+    let (legacy_premium, legacy_claim) = outcome(CommissionMode::Legacy);
+    let (explicit_premium, explicit_claim) = outcome(CommissionMode::Explicit);
+
+    // 보험료 현금 흐름(재보험사 순수취, 참여사 수취)은 같지만 기록되는 출재 보험료/수수료가 다르다.
+    assert_eq!(
+        legacy_premium.reinsurer_net(),
+        explicit_premium.reinsurer_net()
+    );
+    assert_eq!(
+        legacy_premium.insurer_payouts().unwrap(),
+        explicit_premium.insurer_payouts().unwrap()
+    );
+    assert_eq!(
+        (
+            legacy_premium.ceded_premium,
+            legacy_premium.ceding_commission
+        ),
+        (2_250_000, 0)
+    );
+    assert_eq!(
+        (
+            explicit_premium.ceded_premium,
+            explicit_premium.ceding_commission
+        ),
+        (2_500_000, 250_000)
+    );
+
+    // 보험금은 Explicit 모드가 출재율 그대로 출재해 재보험사 부담이 더 크다.
+    assert_eq!(legacy_claim.reinsurer_amount, 36_000_000);
+    assert_eq!(explicit_claim.reinsurer_amount, 40_000_000);
+    assert_eq!(
+        legacy_claim.insurer_amounts,
+        vec![22_000_000, 13_200_000, 8_800_000]
+    );
+    assert_eq!(
+        explicit_claim.insurer_amounts,
+        vec![20_000_000, 12_000_000, 8_000_000]
+    );
 }
@@ -0,0 +1,453 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::create_flight_policy_from_master::{
+    flight_ceded_liability, pay_treasury, record_premium_deductions, record_release,
+};
+use super::file_flight_dispute::is_challenge_period_over;
+use super::manage_master_roles::has_master_role;
+use super::settle_flight_claim::{
+    allocate_received, calc_claim_split, claim_cession_bps, collect_from_layer_pool,
+    pay_beneficiary, record_loss_experience, record_xol_event_loss, recorded_claims_paid,
+    LossExperience,
+};
+use super::settle_flight_no_claim::{
+    calc_no_claim_split, distributable_premium, premium_cession_terms, record_cession,
+    settlement_deductions,
+};
+
+#[derive(Accounts)]
+pub struct SettleFlightsBatch<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub reinsurer_pool_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub reinsurer_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    /// XoL 레이어 부담분이 있는 경우 필요
+    #[account(mut)]
+    pub xol_pool_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// stop-loss 회수분이 있는 경우 필요
+    #[account(mut)]
+    pub stop_loss_pool_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 프로토콜 수수료 차감 시 필요
+    #[account(mut)]
+    pub fee_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    /// 보험료 세금 차감 시 필요
+    #[account(mut)]
+    pub tax_treasury_token: Option<InterfaceAccount<'info, TokenAccount>>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining_accounts 구성:
+/// [FlightPolicy × flight_count] + [참여사 pool_wallet × N] + [참여사 deposit_wallet × N]
+/// + [Claimable 항공편마다(항공편 순서) 수익자 지정 시 beneficiary_token, XoL 사용 시 xol_event PDA]
+pub fn handler<'a>(
+    ctx: Context<'_, '_, 'a, 'a, SettleFlightsBatch<'a>>,
+    flight_count: u8,
+) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+
+    // 단건 정산과 동일한 마스터 상태/권한/지갑 검증을 먼저 수행한다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        has_master_role(master, ctx.accounts.executor.key(), MasterRole::Settler),
+        OpenParamError::Unauthorized
+    );
+    require!(
+        ctx.accounts.leader_deposit_token.key() == master.leader_deposit_wallet,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.reinsurer_pool_token.key() == master.reinsurer_pool_wallet,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.reinsurer_deposit_token.key() == master.reinsurer_deposit_wallet,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.leader_deposit_token.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
+    require!(
+        ctx.accounts.reinsurer_pool_token.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+
+    let k = flight_count as usize;
+    let n = master.participants.len();
+    require!(k > 0 && k <= MAX_SETTLE_BATCH, OpenParamError::InvalidInput);
+    require!(
+        ctx.remaining_accounts.len() >= k + 2 * n,
+        OpenParamError::InvalidAccountList
+    );
+    let (flight_infos, wallet_infos) = ctx.remaining_accounts.split_at(k);
+    let (pool_infos, wallet_infos) = wallet_infos.split_at(n);
+    let (deposit_infos, claim_infos) = wallet_infos.split_at(n);
+    let mut claim_info_iter = claim_infos.iter();
+
+    let insurer_ratios: Vec<u16> = master.participants.iter().map(|p| p.share_bps).collect();
+    let now = Clock::get()?.unix_timestamp;
+
+    // 항공편별 분할 금액을 지갑별로 합산하고, 각 항공편은 개별적으로 Paid/Expired 처리한다.
+    let mut xol_claim_total: u64 = 0;
+    let mut stop_loss_claim_total: u64 = 0;
+    let mut reins_claim_total: u64 = 0;
+    let mut insurer_claim_totals = vec![0u64; n];
+    let mut reins_premium_total: u64 = 0;
+    let mut insurer_premium_totals = vec![0u64; n];
+    let mut ceded_premium_total: u64 = 0;
+    let mut commission_total: u64 = 0;
+    let mut fee_total: u64 = 0;
+    let mut tax_total: u64 = 0;
+    let mut experience = LossExperience::of(master);
+    let mut released_liability: u64 = 0;
+    let mut released_ceded_liability: u64 = 0;
+    let mut claim_payouts: Vec<u64> = Vec::new();
+    let mut beneficiary_payouts: Vec<(usize, Pubkey, &'a AccountInfo<'a>)> = Vec::new();
+
+    for flight_info in flight_infos {
+        let mut flight: Account<FlightPolicy> = Account::try_from(flight_info)?;
+        require!(flight.master == master.key(), OpenParamError::InvalidInput);
+        require!(
+            is_challenge_period_over(flight.challenge_deadline, now),
+            OpenParamError::ChallengePeriodActive
+        );
+
+        if flight.status == FlightPolicyStatus::Claimable as u8 {
+            require!(flight.payout_amount > 0, OpenParamError::InvalidPayout);
+            // stop-loss는 배치 안의 앞선 항공편까지 단건 정산과 같은 순서로 누적해 산정한다.
+            let stop_loss_due = experience.stop_loss_due(
+                flight.premium_paid,
+                flight.payout_amount,
+                master.stop_loss_attachment_bps,
+                master.stop_loss_limit,
+            )?;
+            if flight.beneficiary_token != Pubkey::default() {
+                let beneficiary_info = claim_info_iter
+                    .next()
+                    .ok_or(OpenParamError::InvalidAccountList)?;
+                beneficiary_payouts.push((
+                    claim_payouts.len(),
+                    flight.beneficiary_token,
+                    beneficiary_info,
+                ));
+            }
+            // 같은 사고의 항공편이 배치에 여러 건이면 같은 xol_event 계정을 반복 전달해 누적 적용한다.
+            let xol_amount = if master.xol_limit > 0 {
+                let xol_event_info = claim_info_iter
+                    .next()
+                    .ok_or(OpenParamError::InvalidAccountList)?;
+                record_xol_event_loss(
+                    ctx.program_id,
+                    master,
+                    &flight,
+                    xol_event_info,
+                    ctx.accounts.executor.to_account_info(),
+                    &ctx.accounts.system_program,
+                )?
+            } else {
+                0
+            };
+            let split = calc_claim_split(
+                flight.payout_amount,
+                xol_amount,
+                claim_cession_bps(
+                    master.commission_mode,
+                    flight.ceded_ratio_bps,
+                    flight.reinsurer_effective_bps,
+                ),
+                &insurer_ratios,
+                stop_loss_due,
+            )?;
+            xol_claim_total = xol_claim_total
+                .checked_add(split.xol_amount)
+                .ok_or(OpenParamError::MathOverflow)?;
+            stop_loss_claim_total = stop_loss_claim_total
+                .checked_add(split.stop_loss_amount)
+                .ok_or(OpenParamError::MathOverflow)?;
+            reins_claim_total = reins_claim_total
+                .checked_add(split.reinsurer_amount)
+                .ok_or(OpenParamError::MathOverflow)?;
+            accumulate_split(&mut insurer_claim_totals, &split.insurer_amounts)?;
+            experience.record(
+                flight.premium_paid,
+                flight.payout_amount,
+                split.stop_loss_amount,
+            )?;
+            flight.status = FlightPolicyStatus::Paid as u8;
+            claim_payouts.push(flight.payout_amount);
+        } else if flight.status == FlightPolicyStatus::NoClaim as u8 {
+            require!(!flight.premium_distributed, OpenParamError::AlreadySettled);
+            // 정산 시점 차감분은 항공편에 기록하고 treasury 이체는 합계로 한 번에 한다.
+            let (protocol_fee, premium_tax) = settlement_deductions(master, flight.premium_paid)?;
+            if protocol_fee > 0 || premium_tax > 0 {
+                flight.protocol_fee_paid = protocol_fee;
+                flight.premium_tax_paid = premium_tax;
+                fee_total = fee_total
+                    .checked_add(protocol_fee)
+                    .ok_or(OpenParamError::MathOverflow)?;
+                tax_total = tax_total
+                    .checked_add(premium_tax)
+                    .ok_or(OpenParamError::MathOverflow)?;
+            }
+            let (ceded_ratio_bps, commission_bps) = premium_cession_terms(
+                master.commission_mode,
+                flight.ceded_ratio_bps,
+                flight.reins_commission_bps,
+                flight.reinsurer_effective_bps,
+            );
+            let split = calc_no_claim_split(
+                distributable_premium(&flight)?,
+                ceded_ratio_bps,
+                commission_bps,
+                &insurer_ratios,
+            )?;
+            reins_premium_total = reins_premium_total
+                .checked_add(split.reinsurer_net())
+                .ok_or(OpenParamError::MathOverflow)?;
+            accumulate_split(&mut insurer_premium_totals, &split.insurer_payouts()?)?;
+            ceded_premium_total = ceded_premium_total
+                .checked_add(split.ceded_premium)
+                .ok_or(OpenParamError::MathOverflow)?;
+            commission_total = commission_total
+                .checked_add(split.ceding_commission)
+                .ok_or(OpenParamError::MathOverflow)?;
+            experience.record(flight.premium_paid, 0, 0)?;
+            flight.premium_distributed = true;
+            flight.status = FlightPolicyStatus::Expired as u8;
+        } else {
+            return Err(OpenParamError::InvalidState.into());
+        }
+
+        released_liability = released_liability
+            .checked_add(flight.max_liability)
+            .ok_or(OpenParamError::MathOverflow)?;
+        released_ceded_liability = released_ceded_liability
+            .checked_add(flight_ceded_liability(master.commission_mode, &flight)?)
+            .ok_or(OpenParamError::MathOverflow)?;
+        // 같은 항공편이 중복 전달되면 두 번째 로드에서 상태 검증에 걸리도록 즉시 기록한다.
+        flight.updated_at = now;
+        flight.exit(ctx.program_id)?;
+    }
+    require!(
+        claim_info_iter.next().is_none(),
+        OpenParamError::InvalidAccountList
+    );
+
+    let seed_master_id = master.master_id.to_le_bytes();
+    let seeds = &[
+        b"master_policy".as_ref(),
+        master.seed_leader.as_ref(),
+        seed_master_id.as_ref(),
+        &[master.bump],
+    ];
+    let signer = &[&seeds[..]];
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let master_info = ctx.accounts.master_policy.to_account_info();
+    let leader_deposit_info = ctx.accounts.leader_deposit_token.to_account_info();
+    let currency_mint = &ctx.accounts.currency_mint;
+
+    // 청구분으로 리더 deposit에 실제 모인 금액(transfer fee 차감 후) 합계.
+    let mut collected: u64 = 0;
+    if xol_claim_total > 0 {
+        collected = collected
+            .checked_add(collect_from_layer_pool(
+                master,
+                ctx.accounts.xol_pool_token.as_ref(),
+                master.xol_pool_wallet,
+                &ctx.accounts.token_program,
+                currency_mint,
+                &ctx.accounts.leader_deposit_token,
+                xol_claim_total,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
+    if stop_loss_claim_total > 0 {
+        collected = collected
+            .checked_add(collect_from_layer_pool(
+                master,
+                ctx.accounts.stop_loss_pool_token.as_ref(),
+                master.stop_loss_pool_wallet,
+                &ctx.accounts.token_program,
+                currency_mint,
+                &ctx.accounts.leader_deposit_token,
+                stop_loss_claim_total,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
+    if reins_claim_total > 0 {
+        // 재보험 풀에서 리더 deposit으로 재보험사 부담분 합계를 이동한다.
+        collected = collected
+            .checked_add(transfer_checked_received(
+                token_program.clone(),
+                ctx.accounts.reinsurer_pool_token.to_account_info(),
+                currency_mint,
+                leader_deposit_info.clone(),
+                master_info.clone(),
+                signer,
+                reins_claim_total,
+            )?)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
+    if reins_premium_total > 0 {
+        // 리더 deposit의 premium 중 재보험사 몫 합계를 재보험사 deposit으로 보낸다.
+        transfer_checked_received(
+            token_program.clone(),
+            leader_deposit_info.clone(),
+            currency_mint,
+            ctx.accounts.reinsurer_deposit_token.to_account_info(),
+            master_info.clone(),
+            signer,
+            reins_premium_total,
+        )?;
+    }
+    // 무청구 정산 시점 차감분 합계를 treasury로 보낸다.
+    pay_treasury(
+        ctx.accounts.fee_treasury_token.as_ref(),
+        master.fee_treasury_token,
+        token_program.clone(),
+        currency_mint,
+        leader_deposit_info.clone(),
+        master_info.clone(),
+        signer,
+        fee_total,
+    )?;
+    pay_treasury(
+        ctx.accounts.tax_treasury_token.as_ref(),
+        master.tax_treasury_token,
+        token_program.clone(),
+        currency_mint,
+        leader_deposit_info.clone(),
+        master_info.clone(),
+        signer,
+        tax_total,
+    )?;
+
+    for (i, participant) in master.participants.iter().enumerate() {
+        if insurer_claim_totals[i] > 0 {
+            let pool_wallet: InterfaceAccount<TokenAccount> =
+                InterfaceAccount::try_from(&pool_infos[i])?;
+            require!(
+                pool_wallet.key() == participant.pool_wallet,
+                OpenParamError::InvalidInput
+            );
+            require!(
+                pool_wallet.owner == master.key(),
+                OpenParamError::InvalidSettlementTarget
+            );
+            // 참여사 풀 지갑에서 리더 deposit으로 부담분 합계를 이체한다.
+            collected = collected
+                .checked_add(transfer_checked_received(
+                    token_program.clone(),
+                    pool_infos[i].to_account_info(),
+                    currency_mint,
+                    leader_deposit_info.clone(),
+                    master_info.clone(),
+                    signer,
+                    insurer_claim_totals[i],
+                )?)
+                .ok_or(OpenParamError::MathOverflow)?;
+        }
+        if insurer_premium_totals[i] > 0 {
+            let deposit_wallet: InterfaceAccount<TokenAccount> =
+                InterfaceAccount::try_from(&deposit_infos[i])?;
+            require!(
+                deposit_wallet.key() == participant.deposit_wallet,
+                OpenParamError::InvalidInput
+            );
+            // 참여사 deposit 지갑으로 premium 몫과 출재 수수료 몫의 합계를 한 번에 이체한다.
+            transfer_checked_received(
+                token_program.clone(),
+                leader_deposit_info.clone(),
+                currency_mint,
+                deposit_infos[i].to_account_info(),
+                master_info.clone(),
+                signer,
+                insurer_premium_totals[i],
+            )?;
+        }
+    }
+
+    // 각 수익자 지정 건은 합산 수취가 끝난 뒤 실제 모인 금액 중 해당 건 몫을 개별 지급하고,
+    // 지급 보험금 실적에는 리더 deposit 몫 대신 수익자 계정에 실제 입금된 금액을 반영한다.
+    let received_payouts = allocate_received(&claim_payouts, collected)?;
+    let mut deliveries = Vec::with_capacity(beneficiary_payouts.len());
+    for (claim_idx, beneficiary, beneficiary_info) in beneficiary_payouts {
+        let beneficiary_token: InterfaceAccount<TokenAccount> =
+            InterfaceAccount::try_from(beneficiary_info)?;
+        let delivered = pay_beneficiary(
+            &ctx.accounts.master_policy,
+            beneficiary,
+            received_payouts[claim_idx],
+            &ctx.accounts.token_program,
+            currency_mint,
+            &ctx.accounts.leader_deposit_token,
+            &beneficiary_token,
+        )?;
+        deliveries.push((received_payouts[claim_idx], delivered));
+    }
+    let claims_paid_recorded = recorded_claims_paid(collected, &deliveries)?;
+
+    // 정산 완료 건수만큼 미정산 집계를 줄이고 누적 손해 실적에 더한다.
+    let master = &mut ctx.accounts.master_policy;
+    record_release(
+        master,
+        k as u64,
+        released_liability,
+        released_ceded_liability,
+    )?;
+    record_loss_experience(master, &experience, claims_paid_recorded)?;
+    record_premium_deductions(master, fee_total, tax_total)?;
+    record_cession(
+        master,
+        ceded_premium_total,
+        commission_total,
+        reins_claim_total,
+    )?;
+
+    Ok(())
+}
+
+pub(crate) fn accumulate_split(
+    totals: &mut [u64],
+    parts: &[u64],
+) -> std::result::Result<(), OpenParamError> {
+    // 참여사별 분할 금액을 같은 인덱스의 합계에 더한다.
+    if totals.len() != parts.len() {
+        return Err(OpenParamError::InvalidAccountList);
+    }
+    for (total, part) in totals.iter_mut().zip(parts) {
+        *total = total
+            .checked_add(*part)
+            .ok_or(OpenParamError::MathOverflow)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::errors::OpenParamError;
+
+use super::settle_flight_claim::{calc_claim_split, LossExperience};
+use super::settle_flights_batch::accumulate_split;
+
+#[test]
+fn batch_totals_equal_sum_of_per_flight_splits() {
+    // 80 USDC 청구 2건을 합산하면 참여사별 금액도 단건 분할의 2배가 된다.
+    let ratios = [5_000, 3_000, 2_000];
+    let mut totals = vec![0u64; 3];
+    let mut reins_total = 0u64;
+    for _ in 0..2 {
+        let split = calc_claim_split(80_000_000, 0, 4_500, &ratios, 0).unwrap();
+        reins_total += split.reinsurer_amount;
+        accumulate_split(&mut totals, &split.insurer_amounts).unwrap();
+    }
+    assert_eq!(reins_total, 72_000_000);
+    assert_eq!(totals, vec![44_000_000, 26_400_000, 17_600_000]);
+}
+
+#[test]
+fn accumulate_rejects_mismatched_participant_count() {
+    // 참여사 수가 다른 분할 결과는 합산할 수 없다.
+    let mut totals = vec![0u64; 2];
+    assert!(matches!(
+        accumulate_split(&mut totals, &[1, 2, 3]),
+        Err(OpenParamError::InvalidAccountList)
+    ));
+}
+
+#[test]
+fn single_and_batch_settlement_reach_same_stop_loss_state() {
+    // (보험료, payout) 순서: 무청구 10 USDC, 청구 80 USDC, 청구 40 USDC. attachment 50%.
+    let flights = [
+        (10_000_000u64, 0u64),
+        (10_000_000, 80_000_000),
+        (10_000_000, 40_000_000),
+    ];
+    let ratios = [5_000, 3_000, 2_000];
+    let settle = |experience: &mut LossExperience, premium: u64, payout: u64| -> u64 {
+        if payout == 0 {
+            experience.record(premium, 0, 0).unwrap();
+            return 0;
+        }
+        let due = experience
+            .stop_loss_due(premium, payout, 5_000, 1_000_000_000)
+            .unwrap();
+        let split = calc_claim_split(payout, 0, 4_500, &ratios, due).unwrap();
+        experience
+            .record(premium, payout, split.stop_loss_amount)
+            .unwrap();
+        split.stop_loss_amount
+    };
+    let initial = LossExperience {
+        earned_premium: 0,
+        incurred_claims: 0,
+        stop_loss_recovered: 0,
+    };
+
+    // 단건 정산: 항공편마다 저장된 마스터 상태에서 시작해 결과를 다시 저장한다.
+    let mut stored = initial;
+    let mut single_recoveries = Vec::new();
+    for (premium, payout) in flights {
+        let mut experience = stored;
+        single_recoveries.push(settle(&mut experience, premium, payout));
+        stored = experience;
+    }
+
+    // 배치 정산: 하나의 상태로 같은 항공편을 순서대로 처리한 뒤 한 번에 저장한다.
+    let mut batch = initial;
+    let batch_recoveries: Vec<u64> = flights
+        .iter()
+        .map(|(premium, payout)| settle(&mut batch, *premium, *payout))
+        .collect();
+
+    assert_eq!(single_recoveries, batch_recoveries);
+    assert_eq!(single_recoveries, vec![0, 70_000_000, 35_000_000]);
+    assert_eq!(stored, batch);
+    assert_eq!(
+        batch,
+        LossExperience {
+            earned_premium: 30_000_000,
+            incurred_claims: 120_000_000,
+            stop_loss_recovered: 105_000_000,
+        }
+    );
+}
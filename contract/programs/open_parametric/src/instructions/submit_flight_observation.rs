@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::state::*;
+
+use super::resolve_flight_delay::apply_flight_resolution;
+
+#[derive(Accounts)]
+pub struct SubmitFlightObservation<'info> {
+    #[account(mut)]
+    pub resolver: Signer<'info>,
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+    #[account(
+        init_if_needed,
+        payer = resolver,
+        space = FLIGHT_RESOLUTION_SPACE,
+        seeds = [b"flight_resolution", flight_policy.key().as_ref()],
+        bump
+    )]
+    pub flight_resolution: Account<'info, FlightResolution>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ObservationOutcome {
+    Pending,
+    Agreed { delay_minutes: u16, cancelled: bool },
+    Disputed,
+}
+
+pub fn handler(
+    ctx: Context<SubmitFlightObservation>,
+    delay_minutes: u16,
+    cancelled: bool,
+) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+    let resolution = &mut ctx.accounts.flight_resolution;
+
+    // 등록된 resolver만 관측값을 제출할 수 있다.
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8,
+        OpenParamError::MasterClosed
+    );
+    require!(
+        master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::MasterCancelled
+    );
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        master.resolvers.contains(&ctx.accounts.resolver.key()),
+        OpenParamError::Unauthorized
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        flight.status == FlightPolicyStatus::AwaitingOracle as u8
+            || flight.status == FlightPolicyStatus::Issued as u8
+            || flight.status == FlightPolicyStatus::Disputed as u8,
+        OpenParamError::InvalidState
+    );
+
+    // 최초 제출 시 항공편별 resolution 계정을 초기화한다.
+    if resolution.flight_policy == Pubkey::default() {
+        resolution.flight_policy = flight.key();
+        resolution.master = master.key();
+        resolution.observations = vec![];
+        resolution.finalized = false;
+        resolution.bump = ctx.bumps.flight_resolution;
+    }
+    require!(
+        resolution.flight_policy == flight.key(),
+        OpenParamError::InvalidInput
+    );
+    require!(!resolution.finalized, OpenParamError::AlreadySettled);
+    require!(
+        !resolution
+            .observations
+            .iter()
+            .any(|o| o.resolver == ctx.accounts.resolver.key()),
+        OpenParamError::AlreadyExists
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    resolution.observations.push(ResolverObservation {
+        resolver: ctx.accounts.resolver.key(),
+        delay_minutes,
+        cancelled,
+        submitted_at: now,
+    });
+
+    // 합의 도달 시에만 결과를 확정하고, 어떤 결과도 threshold에 도달할 수 없으면 Disputed로 둔다.
+    match evaluate_observations(
+        &resolution.observations,
+        master.resolver_threshold,
+        master.resolvers.len(),
+    ) {
+        ObservationOutcome::Agreed {
+            delay_minutes,
+            cancelled,
+        } => {
+            apply_flight_resolution(master, flight, delay_minutes, cancelled, now);
+            resolution.finalized = true;
+        }
+        ObservationOutcome::Disputed => {
+            flight.disputed_at = quorum_dispute_start(flight.status, flight.disputed_at, now);
+            if flight.status != FlightPolicyStatus::Disputed as u8 {
+                flight.pre_dispute_status = flight.status;
+                flight.disputed_by = ctx.accounts.resolver.key();
+            }
+            flight.status = FlightPolicyStatus::Disputed as u8;
+            flight.updated_at = now;
+        }
+        ObservationOutcome::Pending => {}
+    }
+
+    Ok(())
+}
+
+pub(crate) fn quorum_dispute_start(status: u8, disputed_at: i64, now: i64) -> i64 {
+    // 이미 분쟁 중이면 기존 시작 시각을 유지하고, 새로 분쟁에 들어갈 때만 현재 시각을 기록한다.
+    if status == FlightPolicyStatus::Disputed as u8 {
+        disputed_at
+    } else {
+        now
+    }
+}
+
+pub(crate) fn evaluate_observations(
+    observations: &[ResolverObservation],
+    threshold: u8,
+    resolver_count: usize,
+) -> ObservationOutcome {
+    // 동일한 (지연 분, 결항) 관측이 threshold건 모이면 합의로 확정한다.
+    if threshold == 0 {
+        return ObservationOutcome::Pending;
+    }
+    let threshold = threshold as usize;
+    let mut best = 0usize;
+    for o in observations {
+        let count = observations
+            .iter()
+            .filter(|x| x.delay_minutes == o.delay_minutes && x.cancelled == o.cancelled)
+            .count();
+        if count >= threshold {
+            return ObservationOutcome::Agreed {
+                delay_minutes: o.delay_minutes,
+                cancelled: o.cancelled,
+            };
+        }
+        best = best.max(count);
+    }
+    // 남은 resolver가 모두 최다 관측에 동의해도 threshold에 못 미칠 때만 Disputed로 본다.
+    let remaining = resolver_count.saturating_sub(observations.len());
+    if best + remaining < threshold {
+        return ObservationOutcome::Disputed;
+    }
+    ObservationOutcome::Pending
+}
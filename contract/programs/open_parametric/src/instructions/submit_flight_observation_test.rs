@@ -0,0 +1,123 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::state::{FlightPolicyStatus, ResolverObservation};
+
+use super::resolve_flight_dispute::is_dispute_expired;
+use super::submit_flight_observation::{
+    evaluate_observations, quorum_dispute_start, ObservationOutcome,
+};
+
+fn observation(delay_minutes: u16, cancelled: bool) -> ResolverObservation {
+    ResolverObservation {
+        resolver: Pubkey::new_unique(),
+        delay_minutes,
+        cancelled,
+        submitted_at: 0,
+    }
+}
+
+#[test]
+fn observations_finalize_once_threshold_agrees() {
+    // 2-of-3 구성에서 동일 관측 2건이면 합의로 확정된다.
+    let one = vec![observation(240, false)];
+    assert_eq!(
+        evaluate_observations(&one, 2, 3),
+        ObservationOutcome::Pending
+    );
+
+    let two = vec![observation(240, false), observation(240, false)];
+    assert_eq!(
+        evaluate_observations(&two, 2, 3),
+        ObservationOutcome::Agreed {
+            delay_minutes: 240,
+            cancelled: false
+        }
+    );
+}
+
+#[test]
+fn threshold_agreement_finalizes_despite_dissent() {
+    // M건이 일치하면 반대 관측 1건이 있어도 다수 결과로 확정된다.
+    let dissent_last = vec![
+        observation(0, true),
+        observation(0, true),
+        observation(0, false),
+    ];
+    assert_eq!(
+        evaluate_observations(&dissent_last, 2, 3),
+        ObservationOutcome::Agreed {
+            delay_minutes: 0,
+            cancelled: true
+        }
+    );
+
+    let dissent_first = vec![
+        observation(120, false),
+        observation(240, false),
+        observation(240, false),
+    ];
+    assert_eq!(
+        evaluate_observations(&dissent_first, 2, 3),
+        ObservationOutcome::Agreed {
+            delay_minutes: 240,
+            cancelled: false
+        }
+    );
+}
+
+#[test]
+fn disagreement_waits_while_threshold_is_still_reachable() {
+    // 남은 resolver로 threshold에 도달할 수 있으면 Pending을 유지한다.
+    let split = vec![observation(240, false), observation(120, false)];
+    assert_eq!(
+        evaluate_observations(&split, 2, 3),
+        ObservationOutcome::Pending
+    );
+    assert_eq!(
+        evaluate_observations(&split, 3, 4),
+        ObservationOutcome::Pending
+    );
+}
+
+#[test]
+fn unreachable_threshold_marks_dispute() {
+    // 어떤 결과도 threshold에 도달할 수 없으면 Disputed.
+    let split = vec![observation(240, false), observation(120, false)];
+    assert_eq!(
+        evaluate_observations(&split, 2, 2),
+        ObservationOutcome::Disputed
+    );
+
+    let three_way = vec![
+        observation(0, true),
+        observation(240, false),
+        observation(0, false),
+    ];
+    assert_eq!(
+        evaluate_observations(&three_way, 2, 3),
+        ObservationOutcome::Disputed
+    );
+}
+
+#[test]
+fn quorum_dispute_cannot_expire_immediately() {
+    // 관측 불일치로 분쟁이 된 시각부터 대기 시간이 흘러야 만료 처리할 수 있다.
+    let now: i64 = 1_700_000_000;
+    let split = vec![observation(240, false), observation(120, false)];
+    assert_eq!(
+        evaluate_observations(&split, 2, 2),
+        ObservationOutcome::Disputed
+    );
+
+    let disputed_at = quorum_dispute_start(FlightPolicyStatus::AwaitingOracle as u8, 0, now);
+    assert_eq!(disputed_at, now);
+    assert!(!is_dispute_expired(disputed_at, 24, now).unwrap());
+    assert!(!is_dispute_expired(disputed_at, 24, now + 24 * 3_600 - 1).unwrap());
+    assert!(is_dispute_expired(disputed_at, 24, now + 24 * 3_600).unwrap());
+
+    // 이미 분쟁 중인 정책에 관측이 추가돼도 분쟁 시작 시각은 밀리지 않는다.
+    assert_eq!(
+        quorum_dispute_start(FlightPolicyStatus::Disputed as u8, disputed_at, now + 60),
+        disputed_at
+    );
+}
@@ -0,0 +1,142 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::OpenParamError;
+use crate::events::{MasterAuthorityProposed, MasterAuthorityTransferred};
+use crate::state::*;
+
+// ─── Propose ──────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct ProposeMasterAuthority<'info> {
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+}
+
+pub fn propose_handler(
+    ctx: Context<ProposeMasterAuthority>,
+    role: u8,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8
+            && master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::InvalidState
+    );
+    let role = parse_authority(role)?;
+    require!(
+        can_propose_authority(
+            master.leader,
+            master.operator,
+            ctx.accounts.authority.key(),
+            role
+        ),
+        OpenParamError::Unauthorized
+    );
+
+    // 후보만 기록하고 인계 대상이 직접 수락해야 권한이 넘어간다.
+    // Pubkey::default()를 제안하면 진행 중인 인계를 철회한다.
+    let current = match role {
+        MasterAuthority::Leader => master.leader,
+        MasterAuthority::Operator => master.operator,
+    };
+    require!(new_authority != current, OpenParamError::InvalidInput);
+    require!(
+        role != MasterAuthority::Leader
+            || new_authority == Pubkey::default()
+            || is_master_participant(&master.participants, new_authority),
+        OpenParamError::InvalidInput
+    );
+    match role {
+        MasterAuthority::Leader => master.pending_leader = new_authority,
+        MasterAuthority::Operator => master.pending_operator = new_authority,
+    }
+
+    emit!(MasterAuthorityProposed {
+        master: master.key(),
+        role: role as u8,
+        current,
+        proposed: new_authority,
+    });
+    Ok(())
+}
+
+// ─── Accept ───────────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct AcceptMasterAuthority<'info> {
+    pub new_authority: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+}
+
+pub fn accept_handler(ctx: Context<AcceptMasterAuthority>, role: u8) -> Result<()> {
+    let master = &mut ctx.accounts.master_policy;
+    require!(
+        master.status != MasterPolicyStatus::Closed as u8
+            && master.status != MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::InvalidState
+    );
+    let role = parse_authority(role)?;
+    let signer = ctx.accounts.new_authority.key();
+
+    // PDA seed는 seed_leader를 쓰므로 leader가 바뀌어도 마스터 주소와 지갑 권한은 유지된다.
+    // 참여사 지분(participants)은 인계 대상이 아니므로 새 leader는 이미 참여사여야 한다.
+    let previous = match role {
+        MasterAuthority::Leader => {
+            require!(
+                master.pending_leader != Pubkey::default() && master.pending_leader == signer,
+                OpenParamError::Unauthorized
+            );
+            require!(
+                is_master_participant(&master.participants, signer),
+                OpenParamError::Unauthorized
+            );
+            master.pending_leader = Pubkey::default();
+            std::mem::replace(&mut master.leader, signer)
+        }
+        MasterAuthority::Operator => {
+            require!(
+                master.pending_operator != Pubkey::default() && master.pending_operator == signer,
+                OpenParamError::Unauthorized
+            );
+            master.pending_operator = Pubkey::default();
+            std::mem::replace(&mut master.operator, signer)
+        }
+    };
+
+    emit!(MasterAuthorityTransferred {
+        master: master.key(),
+        role: role as u8,
+        previous,
+        new_authority: signer,
+    });
+    Ok(())
+}
+
+pub(crate) fn parse_authority(role: u8) -> std::result::Result<MasterAuthority, OpenParamError> {
+    match role {
+        r if r == MasterAuthority::Leader as u8 => Ok(MasterAuthority::Leader),
+        r if r == MasterAuthority::Operator as u8 => Ok(MasterAuthority::Operator),
+        _ => Err(OpenParamError::InvalidInput),
+    }
+}
+
+pub(crate) fn can_propose_authority(
+    leader: Pubkey,
+    operator: Pubkey,
+    signer: Pubkey,
+    role: MasterAuthority,
+) -> bool {
+    // leader 인계는 현 leader만, operator 인계는 leader 또는 현 operator가 제안할 수 있다.
+    match role {
+        MasterAuthority::Leader => signer == leader,
+        MasterAuthority::Operator => signer == leader || signer == operator,
+    }
+}
+
+pub(crate) fn is_master_participant(participants: &[MasterParticipant], candidate: Pubkey) -> bool {
+    // leader는 리더 지분을 가진 참여사여야 하므로 participants에 있는 계정만 leader가 될 수 있다.
+    participants.iter().any(|p| p.insurer == candidate)
+}
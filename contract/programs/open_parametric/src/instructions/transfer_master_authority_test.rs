@@ -0,0 +1,75 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::errors::OpenParamError;
+use crate::state::{MasterAuthority, MasterParticipant};
+
+use super::transfer_master_authority::{
+    can_propose_authority, is_master_participant, parse_authority,
+};
+
+#[test]
+fn only_leader_proposes_leader_handover() {
+    let leader = Pubkey::new_unique();
+    let operator = Pubkey::new_unique();
+    assert!(can_propose_authority(
+        leader,
+        operator,
+        leader,
+        MasterAuthority::Leader
+    ));
+    assert!(!can_propose_authority(
+        leader,
+        operator,
+        operator,
+        MasterAuthority::Leader
+    ));
+}
+
+#[test]
+fn leader_or_operator_proposes_operator_handover() {
+    // 운영 키 교체는 현 operator 스스로도 제안할 수 있다.
+    let leader = Pubkey::new_unique();
+    let operator = Pubkey::new_unique();
+    assert!(can_propose_authority(
+        leader,
+        operator,
+        leader,
+        MasterAuthority::Operator
+    ));
+    assert!(can_propose_authority(
+        leader,
+        operator,
+        operator,
+        MasterAuthority::Operator
+    ));
+    assert!(!can_propose_authority(
+        leader,
+        operator,
+        Pubkey::new_unique(),
+        MasterAuthority::Operator
+    ));
+    assert!(matches!(
+        parse_authority(2),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn leader_handover_requires_participant() {
+    // 지분이 없는 계정은 leader를 인계받을 수 없고, 기존 참여사만 가능하다.
+    let participant = |insurer: Pubkey| MasterParticipant {
+        insurer,
+        share_bps: 5_000,
+        confirmed: true,
+        pool_wallet: Pubkey::new_unique(),
+        deposit_wallet: Pubkey::new_unique(),
+        capital_deposited: 0,
+        capital_withdrawn: 0,
+    };
+    let leader = Pubkey::new_unique();
+    let partner = Pubkey::new_unique();
+    let participants = vec![participant(leader), participant(partner)];
+    assert!(is_master_participant(&participants, partner));
+    assert!(!is_master_participant(&participants, Pubkey::new_unique()));
+    assert!(!is_master_participant(&participants, Pubkey::default()));
+}
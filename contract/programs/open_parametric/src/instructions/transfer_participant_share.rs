@@ -0,0 +1,234 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::*;
+use crate::errors::OpenParamError;
+use crate::math::BPS_DENOM;
+use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+#[derive(Accounts)]
+pub struct TransferParticipantShare<'info> {
+    pub leader: Signer<'info>,
+    pub outgoing_insurer: Signer<'info>,
+    pub incoming_insurer: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub outgoing_pool_wallet: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub incoming_pool_wallet: InterfaceAccount<'info, TokenAccount>,
+    pub incoming_deposit_wallet: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<TransferParticipantShare>, share_bps: u16) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+
+    // 보장 기간 중 지분 양도는 양도/양수 보험사 서명과 리더 동의가 모두 필요하다.
+    require!(
+        master.status == MasterPolicyStatus::Active as u8,
+        OpenParamError::MasterNotActive
+    );
+    require!(
+        ctx.accounts.leader.key() == master.leader,
+        OpenParamError::Unauthorized
+    );
+    let outgoing = ctx.accounts.outgoing_insurer.key();
+    let incoming = ctx.accounts.incoming_insurer.key();
+    let out_idx = master
+        .participants
+        .iter()
+        .position(|p| p.insurer == outgoing)
+        .ok_or(OpenParamError::NotFound)?;
+    let outgoing_share_bps = master.participants[out_idx].share_bps;
+
+    require!(
+        ctx.accounts.outgoing_pool_wallet.key() == master.participants[out_idx].pool_wallet,
+        OpenParamError::InvalidInput
+    );
+    // 양수사 풀 지갑은 마스터 PDA 소유여야 정산/예약 검증에 쓸 수 있다.
+    require!(
+        ctx.accounts.incoming_pool_wallet.mint == master.currency_mint
+            && ctx.accounts.incoming_deposit_wallet.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.incoming_pool_wallet.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+
+    // 양도 지분 비율만큼 양도사 풀 자본을 양수사 풀로 옮긴다.
+    let moved_capital = calc_capital_transfer(
+        ctx.accounts.outgoing_pool_wallet.amount,
+        outgoing_share_bps,
+        share_bps,
+    )?;
+    let mut received_capital = 0;
+    if moved_capital > 0 {
+        let seed_master_id = master.master_id.to_le_bytes();
+        let seeds = &[
+            b"master_policy".as_ref(),
+            master.seed_leader.as_ref(),
+            seed_master_id.as_ref(),
+            &[master.bump],
+        ];
+        let signer = &[&seeds[..]];
+        received_capital = transfer_checked_received(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.outgoing_pool_wallet.to_account_info(),
+            &ctx.accounts.currency_mint,
+            ctx.accounts.incoming_pool_wallet.to_account_info(),
+            master.to_account_info(),
+            signer,
+            moved_capital,
+        )?;
+    }
+
+    let leader = master.leader;
+    let master_wallets = master_level_wallets(master);
+    let incoming_pool = ctx.accounts.incoming_pool_wallet.key();
+    let incoming_deposit = ctx.accounts.incoming_deposit_wallet.key();
+    apply_share_transfer(
+        &mut ctx.accounts.master_policy.participants,
+        leader,
+        &master_wallets,
+        ShareTransfer {
+            outgoing,
+            incoming,
+            share_bps,
+            incoming_pool,
+            incoming_deposit,
+            moved_capital,
+            received_capital,
+        },
+    )?;
+
+    Ok(())
+}
+
+pub(crate) struct ShareTransfer {
+    pub outgoing: Pubkey,
+    pub incoming: Pubkey,
+    pub share_bps: u16,
+    pub incoming_pool: Pubkey,
+    pub incoming_deposit: Pubkey,
+    pub moved_capital: u64,
+    // transfer fee 차감 후 양수사 풀에 실제 입금된 자본.
+    pub received_capital: u64,
+}
+
+pub(crate) fn master_level_wallets(master: &MasterPolicy) -> Vec<Pubkey> {
+    // 재보험/레이어 풀과 리더·재보험사 deposit 등 참여사 지갑으로 쓸 수 없는 마스터 단위 지갑.
+    [
+        master.reinsurer_pool_wallet,
+        master.reinsurer_deposit_wallet,
+        master.xol_pool_wallet,
+        master.stop_loss_pool_wallet,
+        master.leader_deposit_wallet,
+    ]
+    .into_iter()
+    .filter(|w| *w != Pubkey::default())
+    .collect()
+}
+
+pub(crate) fn calc_capital_transfer(
+    pool_balance: u64,
+    outgoing_share_bps: u16,
+    share_bps: u16,
+) -> std::result::Result<u64, OpenParamError> {
+    if share_bps == 0 || share_bps > outgoing_share_bps {
+        return Err(OpenParamError::InvalidRatio);
+    }
+    // 전량 양도는 풀 잔액 전체를, 일부 양도는 지분 비율만큼(내림) 옮긴다.
+    if share_bps == outgoing_share_bps {
+        return Ok(pool_balance);
+    }
+    let moved = (pool_balance as u128)
+        .checked_mul(share_bps as u128)
+        .ok_or(OpenParamError::MathOverflow)?
+        / outgoing_share_bps as u128;
+    u64::try_from(moved).map_err(|_| OpenParamError::MathOverflow)
+}
+
+pub(crate) fn apply_share_transfer(
+    participants: &mut Vec<MasterParticipant>,
+    leader: Pubkey,
+    master_wallets: &[Pubkey],
+    t: ShareTransfer,
+) -> std::result::Result<(), OpenParamError> {
+    if t.outgoing == t.incoming {
+        return Err(OpenParamError::InvalidInput);
+    }
+    let out_idx = participants
+        .iter()
+        .position(|p| p.insurer == t.outgoing)
+        .ok_or(OpenParamError::NotFound)?;
+    let remaining = participants[out_idx]
+        .share_bps
+        .checked_sub(t.share_bps)
+        .ok_or(OpenParamError::InvalidRatio)?;
+    // 리더는 마스터 참여사로 남아야 하므로 지분 전량을 넘길 수 없다.
+    if t.share_bps == 0 || (t.outgoing == leader && remaining == 0) {
+        return Err(OpenParamError::InvalidRatio);
+    }
+
+    // 자본 이동은 양도사 인출(이체 금액)/양수사 납입(실제 입금액)으로 기록한다.
+    participants[out_idx].share_bps = remaining;
+    participants[out_idx].capital_withdrawn = participants[out_idx]
+        .capital_withdrawn
+        .checked_add(t.moved_capital)
+        .ok_or(OpenParamError::MathOverflow)?;
+
+    if let Some(in_idx) = participants.iter().position(|p| p.insurer == t.incoming) {
+        // 기존 참여사가 양수하면 등록된 정산 지갑을 그대로 써야 한다.
+        let p = &mut participants[in_idx];
+        if p.pool_wallet != t.incoming_pool || p.deposit_wallet != t.incoming_deposit {
+            return Err(OpenParamError::InvalidInput);
+        }
+        p.share_bps = p
+            .share_bps
+            .checked_add(t.share_bps)
+            .ok_or(OpenParamError::MathOverflow)?;
+        p.capital_deposited = p
+            .capital_deposited
+            .checked_add(t.received_capital)
+            .ok_or(OpenParamError::MathOverflow)?;
+    } else {
+        // 신규 양수사 지갑은 다른 참여사 지갑이나 마스터 단위 지갑과 겹칠 수 없다.
+        if participants
+            .iter()
+            .any(|p| p.pool_wallet == t.incoming_pool || p.deposit_wallet == t.incoming_deposit)
+            || master_wallets.contains(&t.incoming_pool)
+            || master_wallets.contains(&t.incoming_deposit)
+        {
+            return Err(OpenParamError::InvalidInput);
+        }
+        participants.push(MasterParticipant {
+            insurer: t.incoming,
+            share_bps: t.share_bps,
+            confirmed: true,
+            pool_wallet: t.incoming_pool,
+            deposit_wallet: t.incoming_deposit,
+            capital_deposited: t.received_capital,
+            capital_withdrawn: 0,
+        });
+    }
+    if remaining == 0 {
+        participants.remove(out_idx);
+    }
+    if participants.len() > MAX_MASTER_PARTICIPANTS {
+        return Err(OpenParamError::InputTooLong);
+    }
+
+    let total: u64 = participants.iter().map(|p| p.share_bps as u64).sum();
+    if total != BPS_DENOM {
+        return Err(OpenParamError::InvalidRatio);
+    }
+    Ok(())
+}
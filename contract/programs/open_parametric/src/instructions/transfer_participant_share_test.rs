@@ -0,0 +1,157 @@
+use anchor_lang::prelude::Pubkey;
+
+use crate::errors::OpenParamError;
+use crate::state::MasterParticipant;
+
+use super::transfer_participant_share::{
+    apply_share_transfer, calc_capital_transfer, ShareTransfer,
+};
+
+fn participant(share_bps: u16) -> MasterParticipant {
+    MasterParticipant {
+        insurer: Pubkey::new_unique(),
+        share_bps,
+        confirmed: true,
+        pool_wallet: Pubkey::new_unique(),
+        deposit_wallet: Pubkey::new_unique(),
+        capital_deposited: 0,
+        capital_withdrawn: 0,
+    }
+}
+
+fn transfer(outgoing: Pubkey, incoming: Pubkey, share_bps: u16, moved: u64) -> ShareTransfer {
+    ShareTransfer {
+        outgoing,
+        incoming,
+        share_bps,
+        incoming_pool: Pubkey::new_unique(),
+        incoming_deposit: Pubkey::new_unique(),
+        moved_capital: moved,
+        received_capital: moved,
+    }
+}
+
+#[test]
+fn capital_moves_pro_rata_to_transferred_share() {
+    // 3000 bps 중 1000 bps 양도 시 풀 30 USDC의 1/3을 옮긴다.
+    assert_eq!(
+        calc_capital_transfer(30_000_000, 3_000, 1_000).unwrap(),
+        10_000_000
+    );
+    assert_eq!(
+        calc_capital_transfer(30_000_001, 3_000, 3_000).unwrap(),
+        30_000_001
+    );
+    assert!(matches!(
+        calc_capital_transfer(30_000_000, 3_000, 3_001),
+        Err(OpenParamError::InvalidRatio)
+    ));
+}
+
+#[test]
+fn full_transfer_replaces_outgoing_with_new_insurer() {
+    let mut participants = vec![participant(5_000), participant(3_000), participant(2_000)];
+    let leader = participants[0].insurer;
+    let outgoing = participants[2].insurer;
+    let incoming = Pubkey::new_unique();
+    apply_share_transfer(
+        &mut participants,
+        leader,
+        &[],
+        transfer(outgoing, incoming, 2_000, 20_000_000),
+    )
+    .unwrap();
+    assert_eq!(participants.len(), 3);
+    assert!(participants.iter().all(|p| p.insurer != outgoing));
+    let new = participants.iter().find(|p| p.insurer == incoming).unwrap();
+    assert_eq!(new.share_bps, 2_000);
+    assert_eq!(new.capital_deposited, 20_000_000);
+}
+
+#[test]
+fn partial_transfer_to_existing_participant_keeps_total() {
+    let mut participants = vec![participant(5_000), participant(3_000), participant(2_000)];
+    let leader = participants[0].insurer;
+    let outgoing = participants[1].insurer;
+    let incoming = participants[2].insurer;
+    let mut t = transfer(outgoing, incoming, 1_000, 10_000_000);
+    t.incoming_pool = participants[2].pool_wallet;
+    t.incoming_deposit = participants[2].deposit_wallet;
+    apply_share_transfer(&mut participants, leader, &[], t).unwrap();
+    assert_eq!(participants[1].share_bps, 2_000);
+    assert_eq!(participants[1].capital_withdrawn, 10_000_000);
+    assert_eq!(participants[2].share_bps, 3_000);
+}
+
+#[test]
+fn leader_cannot_leave_and_wallets_must_match() {
+    let mut participants = vec![participant(5_000), participant(5_000)];
+    let leader = participants[0].insurer;
+    let other = participants[1].insurer;
+    // 리더 지분 전량 양도는 거절한다.
+    assert!(matches!(
+        apply_share_transfer(
+            &mut participants,
+            leader,
+            &[],
+            transfer(leader, Pubkey::new_unique(), 5_000, 0)
+        ),
+        Err(OpenParamError::InvalidRatio)
+    ));
+    // 기존 참여사에게 양도하면서 다른 지갑을 지정하면 거절한다.
+    assert!(matches!(
+        apply_share_transfer(
+            &mut participants,
+            leader,
+            &[],
+            transfer(leader, other, 1_000, 0)
+        ),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
+
+#[test]
+fn transfer_fee_shortfall_is_recorded_on_incoming_side_only() {
+    // transfer fee가 있으면 양도사는 이체 금액을, 양수사는 실제 입금액을 기록한다.
+    let mut participants = vec![participant(5_000), participant(5_000)];
+    let leader = participants[0].insurer;
+    let outgoing = participants[1].insurer;
+    let incoming = Pubkey::new_unique();
+    let mut t = transfer(outgoing, incoming, 2_000, 10_000_000);
+    t.received_capital = 9_900_000;
+    apply_share_transfer(&mut participants, leader, &[], t).unwrap();
+    assert_eq!(participants[1].capital_withdrawn, 10_000_000);
+    let new = participants.iter().find(|p| p.insurer == incoming).unwrap();
+    assert_eq!(new.capital_deposited, 9_900_000);
+}
+
+#[test]
+fn incoming_wallets_cannot_reuse_master_level_wallets() {
+    // 재보험 풀/리더 deposit 등 마스터 단위 지갑을 신규 양수사 지갑으로 지정하면 거절한다.
+    let participants = vec![participant(5_000), participant(5_000)];
+    let leader = participants[0].insurer;
+    let outgoing = participants[1].insurer;
+    let reinsurer_pool = Pubkey::new_unique();
+    let leader_deposit = Pubkey::new_unique();
+    let master_wallets = [reinsurer_pool, leader_deposit];
+
+    let mut t = transfer(outgoing, Pubkey::new_unique(), 1_000, 0);
+    t.incoming_pool = reinsurer_pool;
+    assert!(matches!(
+        apply_share_transfer(&mut participants.clone(), leader, &master_wallets, t),
+        Err(OpenParamError::InvalidInput)
+    ));
+    let mut t = transfer(outgoing, Pubkey::new_unique(), 1_000, 0);
+    t.incoming_deposit = leader_deposit;
+    assert!(matches!(
+        apply_share_transfer(&mut participants.clone(), leader, &master_wallets, t),
+        Err(OpenParamError::InvalidInput)
+    ));
+    assert!(apply_share_transfer(
+        &mut participants.clone(),
+        leader,
+        &master_wallets,
+        transfer(outgoing, Pubkey::new_unique(), 1_000, 0)
+    )
+    .is_ok());
+}
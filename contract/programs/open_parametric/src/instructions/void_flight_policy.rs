@@ -0,0 +1,144 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::OpenParamError;
+use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::create_flight_policy_from_master::release_flight;
+use super::settle_flight_no_claim::distributable_premium;
+
+#[derive(Accounts)]
+pub struct VoidFlightPolicy<'info> {
+    pub executor: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub flight_policy: Account<'info, FlightPolicy>,
+    #[account(mut)]
+    pub leader_deposit_token: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer_token: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<VoidFlightPolicy>) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    let flight = &mut ctx.accounts.flight_policy;
+
+    // 취소된 마스터의 미정산 child 정책만 무효화(보험료 환급)할 수 있다.
+    require!(
+        master.status == MasterPolicyStatus::Cancelled as u8,
+        OpenParamError::InvalidState
+    );
+    require!(
+        ctx.accounts.executor.key() == master.leader
+            || ctx.accounts.executor.key() == master.operator,
+        OpenParamError::Unauthorized
+    );
+    require!(flight.master == master.key(), OpenParamError::InvalidInput);
+    require!(
+        is_voidable(flight.status, flight.premium_distributed),
+        OpenParamError::InvalidState
+    );
+
+    refund_premium(
+        master,
+        flight,
+        &ctx.accounts.token_program,
+        &ctx.accounts.currency_mint,
+        &ctx.accounts.leader_deposit_token,
+        &ctx.accounts.payer_token,
+    )?;
+
+    flight.status = FlightPolicyStatus::Voided as u8;
+    flight.updated_at = Clock::get()?.unix_timestamp;
+
+    let master = &mut ctx.accounts.master_policy;
+    release_flight(master, &ctx.accounts.flight_policy)?;
+    record_premium_refund(master, ctx.accounts.flight_policy.premium_paid)?;
+
+    Ok(())
+}
+
+pub(crate) fn record_premium_refund(master: &mut MasterPolicy, premium: u64) -> Result<()> {
+    // 환급된 정책의 보험료는 발급 보험료 합계에서 뺀다. 경과 보험료는 정산 시점에만 쌓이므로
+    // 환급 대상(미정산) 정책의 보험료는 아직 경과 보험료에 들어가 있지 않다.
+    master.total_premium_collected = master
+        .total_premium_collected
+        .checked_sub(premium)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(())
+}
+
+pub(crate) fn refund_premium<'info>(
+    master: &Account<'info, MasterPolicy>,
+    flight: &FlightPolicy,
+    token_program: &Interface<'info, TokenInterface>,
+    currency_mint: &InterfaceAccount<'info, Mint>,
+    leader_deposit_token: &InterfaceAccount<'info, TokenAccount>,
+    payer_token: &InterfaceAccount<'info, TokenAccount>,
+) -> Result<()> {
+    require!(
+        leader_deposit_token.key() == master.leader_deposit_wallet,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        payer_token.key() == flight.payer_token,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        leader_deposit_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        payer_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        leader_deposit_token.owner == master.key(),
+        OpenParamError::InvalidSettlementTarget
+    );
+
+    // 발급 시 treasury로 보낸 수수료/세금은 환급 대상이 아니다.
+    let refund_amount = distributable_premium(flight)?;
+    if refund_amount == 0 {
+        return Ok(());
+    }
+
+    let seed_master_id = master.master_id.to_le_bytes();
+    let seeds = &[
+        b"master_policy".as_ref(),
+        master.seed_leader.as_ref(),
+        seed_master_id.as_ref(),
+        &[master.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // 리더 deposit에 선납된 보험료를 납입 계정으로 돌려준다.
+    transfer_checked_received(
+        token_program.to_account_info(),
+        leader_deposit_token.to_account_info(),
+        currency_mint,
+        payer_token.to_account_info(),
+        master.to_account_info(),
+        signer,
+        refund_amount,
+    )?;
+    Ok(())
+}
+
+pub(crate) fn is_voidable(status: u8, premium_distributed: bool) -> bool {
+    // 보험금 지급/보험료 분배가 끝나지 않은 상태만 무효화 대상이다.
+    !premium_distributed
+        && (status == FlightPolicyStatus::Issued as u8
+            || status == FlightPolicyStatus::AwaitingOracle as u8
+            || status == FlightPolicyStatus::Claimable as u8
+            || status == FlightPolicyStatus::NoClaim as u8
+            || status == FlightPolicyStatus::Disputed as u8)
+}
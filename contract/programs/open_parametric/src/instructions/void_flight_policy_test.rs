@@ -0,0 +1,22 @@
+use crate::state::FlightPolicyStatus;
+
+use super::void_flight_policy::is_voidable;
+
+#[test]
+fn outstanding_policies_are_voidable() {
+    // 결과 대기/청구 가능/무청구 미분배 상태는 환급 대상이다.
+    assert!(is_voidable(FlightPolicyStatus::Issued as u8, false));
+    assert!(is_voidable(FlightPolicyStatus::AwaitingOracle as u8, false));
+    assert!(is_voidable(FlightPolicyStatus::Claimable as u8, false));
+    assert!(is_voidable(FlightPolicyStatus::NoClaim as u8, false));
+    assert!(is_voidable(FlightPolicyStatus::Disputed as u8, false));
+}
+
+#[test]
+fn settled_or_voided_policies_are_not_voidable() {
+    // 지급/만료/무효 상태 또는 이미 분배된 보험료는 다시 환급할 수 없다.
+    assert!(!is_voidable(FlightPolicyStatus::Paid as u8, false));
+    assert!(!is_voidable(FlightPolicyStatus::Expired as u8, true));
+    assert!(!is_voidable(FlightPolicyStatus::Voided as u8, false));
+    assert!(!is_voidable(FlightPolicyStatus::NoClaim as u8, true));
+}
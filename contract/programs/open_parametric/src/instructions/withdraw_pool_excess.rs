@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::OpenParamError;
+use crate::math::BPS_DENOM;
+use crate::state::*;
+use crate::token_transfer::transfer_checked_received;
+
+use super::create_flight_policy_from_master::calc_reserved_shares;
+
+#[derive(Accounts)]
+pub struct WithdrawPoolExcess<'info> {
+    pub actor: Signer<'info>,
+    #[account(mut)]
+    pub master_policy: Account<'info, MasterPolicy>,
+    #[account(mut)]
+    pub pool_wallet: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+    pub currency_mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler(ctx: Context<WithdrawPoolExcess>, amount: u64) -> Result<()> {
+    let master = &ctx.accounts.master_policy;
+    require!(amount > 0, OpenParamError::InvalidAmount);
+
+    // 풀 지갑 소유 당사자(참여사/재보험사/XoL 재보험사)가 signer인지 검증한다.
+    let party = find_pool_party(
+        master,
+        ctx.accounts.actor.key(),
+        ctx.accounts.pool_wallet.key(),
+    )?;
+    require!(
+        ctx.accounts.destination_token.owner == ctx.accounts.actor.key(),
+        OpenParamError::Unauthorized
+    );
+    require!(
+        ctx.accounts.destination_token.mint == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+    require!(
+        ctx.accounts.currency_mint.key() == master.currency_mint,
+        OpenParamError::InvalidInput
+    );
+
+    // 미정산 최대 부담액 중 본인 몫 + 마스터 여유분은 풀에 남아 있어야 한다.
+    let (reinsurer_reserved, insurer_reserved, xol_reserved) = calc_reserved_shares(master)?;
+    let reserved = match party {
+        PoolParty::Participant(idx) => insurer_reserved[idx],
+        PoolParty::Reinsurer => reinsurer_reserved,
+        PoolParty::XolReinsurer => xol_reserved,
+        // stop-loss는 마스터가 활성인 동안 잔여 누적 한도 전체를 예약한다.
+        PoolParty::StopLossReinsurer => {
+            if master.status == MasterPolicyStatus::Active as u8 {
+                master
+                    .stop_loss_limit
+                    .saturating_sub(master.stop_loss_recovered)
+            } else {
+                0
+            }
+        }
+    };
+    let withdrawable = calc_withdrawable(
+        ctx.accounts.pool_wallet.amount,
+        reserved,
+        master.pool_margin_bps,
+    )?;
+    require!(
+        amount <= withdrawable,
+        OpenParamError::WithdrawalExceedsExcess
+    );
+
+    let seed_master_id = master.master_id.to_le_bytes();
+    let seeds = &[
+        b"master_policy".as_ref(),
+        master.seed_leader.as_ref(),
+        seed_master_id.as_ref(),
+        &[master.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    transfer_checked_received(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.pool_wallet.to_account_info(),
+        &ctx.accounts.currency_mint,
+        ctx.accounts.destination_token.to_account_info(),
+        ctx.accounts.master_policy.to_account_info(),
+        signer,
+        amount,
+    )?;
+
+    let master = &mut ctx.accounts.master_policy;
+    let withdrawn = match party {
+        PoolParty::Participant(idx) => &mut master.participants[idx].capital_withdrawn,
+        PoolParty::Reinsurer => &mut master.reinsurer_capital_withdrawn,
+        PoolParty::XolReinsurer => &mut master.xol_capital_withdrawn,
+        PoolParty::StopLossReinsurer => &mut master.stop_loss_capital_withdrawn,
+    };
+    *withdrawn = withdrawn
+        .checked_add(amount)
+        .ok_or(OpenParamError::MathOverflow)?;
+
+    Ok(())
+}
+
+#[derive(Clone, Copy)]
+pub(crate) enum PoolParty {
+    Participant(usize),
+    Reinsurer,
+    XolReinsurer,
+    StopLossReinsurer,
+}
+
+pub(crate) fn find_pool_party(
+    master: &MasterPolicy,
+    actor: Pubkey,
+    pool_wallet: Pubkey,
+) -> Result<PoolParty> {
+    // 풀 지갑 주소로 당사자를 찾고, 그 당사자 본인이 signer인지 확인한다.
+    let (party, owner) = if let Some(idx) = master
+        .participants
+        .iter()
+        .position(|p| p.pool_wallet == pool_wallet)
+    {
+        (
+            PoolParty::Participant(idx),
+            master.participants[idx].insurer,
+        )
+    } else if pool_wallet == master.reinsurer_pool_wallet {
+        (PoolParty::Reinsurer, master.reinsurer)
+    } else if master.xol_limit > 0 && pool_wallet == master.xol_pool_wallet {
+        (PoolParty::XolReinsurer, master.xol_reinsurer)
+    } else if master.stop_loss_limit > 0 && pool_wallet == master.stop_loss_pool_wallet {
+        (PoolParty::StopLossReinsurer, master.stop_loss_reinsurer)
+    } else {
+        return Err(OpenParamError::InvalidInput.into());
+    };
+    require!(actor == owner, OpenParamError::Unauthorized);
+    Ok(party)
+}
+
+pub(crate) fn calc_withdrawable(
+    balance: u64,
+    reserved: u64,
+    margin_bps: u16,
+) -> std::result::Result<u64, OpenParamError> {
+    // 예약 몫에 여유분 비율을 더한 금액을 초과하는 잔액만 인출할 수 있다.
+    let margin = reserved
+        .checked_mul(margin_bps as u64)
+        .ok_or(OpenParamError::MathOverflow)?
+        / BPS_DENOM;
+    let keep = reserved
+        .checked_add(margin)
+        .ok_or(OpenParamError::MathOverflow)?;
+    Ok(balance.saturating_sub(keep))
+}
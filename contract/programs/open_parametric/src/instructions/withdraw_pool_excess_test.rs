@@ -0,0 +1,21 @@
+use super::withdraw_pool_excess::calc_withdrawable;
+
+#[test]
+fn excess_above_reserved_share_and_margin_is_withdrawable() {
+    // 예약 몫 44 USDC + 여유분 10% = 48.4 USDC를 남기고 나머지를 인출할 수 있다.
+    assert_eq!(
+        calc_withdrawable(100_000_000, 44_000_000, 1_000).unwrap(),
+        51_600_000
+    );
+}
+
+#[test]
+fn nothing_is_withdrawable_when_balance_is_below_requirement() {
+    assert_eq!(calc_withdrawable(40_000_000, 44_000_000, 0).unwrap(), 0);
+}
+
+#[test]
+fn full_balance_is_withdrawable_without_outstanding_liability() {
+    // 미정산 부담이 없으면(종료된 마스터 등) 전액 회수할 수 있다.
+    assert_eq!(calc_withdrawable(12_345, 0, 5_000).unwrap(), 12_345);
+}
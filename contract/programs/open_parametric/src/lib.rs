@@ -2,9 +2,14 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod math;
 pub mod state;
+pub mod token_transfer;
+
+#[cfg(test)]
+mod token_transfer_test;
 
 use instructions::*;
 use state::*;
@@ -85,8 +90,8 @@ pub mod open_parametric {
         instructions::activate_master::handler(ctx)
     }
 
-    pub fn create_flight_policy_from_master(
-        ctx: Context<CreateFlightPolicyFromMaster>,
+    pub fn create_flight_policy_from_master<'a>(
+        ctx: Context<'_, '_, 'a, 'a, CreateFlightPolicyFromMaster<'a>>,
         params: CreateFlightPolicyParams,
     ) -> Result<()> {
         instructions::create_flight_policy_from_master::handler(ctx, params)
@@ -111,4 +116,160 @@ pub mod open_parametric {
     ) -> Result<()> {
         instructions::settle_flight_no_claim::handler(ctx)
     }
+
+    pub fn close_master_policy(ctx: Context<CloseMasterPolicy>) -> Result<()> {
+        instructions::close_master_policy::handler(ctx)
+    }
+
+    pub fn cancel_master_policy(ctx: Context<CancelMasterPolicy>) -> Result<()> {
+        instructions::cancel_master_policy::handler(ctx)
+    }
+
+    pub fn void_flight_policy(ctx: Context<VoidFlightPolicy>) -> Result<()> {
+        instructions::void_flight_policy::handler(ctx)
+    }
+
+    pub fn expire_flight_policy<'a>(
+        ctx: Context<'_, '_, 'a, 'a, ExpireFlightPolicy<'a>>,
+    ) -> Result<()> {
+        instructions::expire_flight_policy::handler(ctx)
+    }
+
+    pub fn resolve_flight_delay_with_quote(
+        ctx: Context<ResolveFlightDelayWithQuote>,
+    ) -> Result<()> {
+        instructions::resolve_flight_delay_with_quote::handler(ctx)
+    }
+
+    pub fn submit_flight_observation(
+        ctx: Context<SubmitFlightObservation>,
+        delay_minutes: u16,
+        cancelled: bool,
+    ) -> Result<()> {
+        instructions::submit_flight_observation::handler(ctx, delay_minutes, cancelled)
+    }
+
+    pub fn file_flight_dispute(ctx: Context<FileFlightDispute>) -> Result<()> {
+        instructions::file_flight_dispute::handler(ctx)
+    }
+
+    pub fn resolve_flight_dispute(
+        ctx: Context<ResolveFlightDispute>,
+        delay_minutes: u16,
+        cancelled: bool,
+    ) -> Result<()> {
+        instructions::resolve_flight_dispute::handler(ctx, delay_minutes, cancelled)
+    }
+
+    pub fn expire_flight_dispute(ctx: Context<ExpireFlightDispute>) -> Result<()> {
+        instructions::resolve_flight_dispute::expire_handler(ctx)
+    }
+
+    pub fn create_flight_policies_batch<'a>(
+        ctx: Context<'_, '_, 'a, 'a, CreateFlightPoliciesBatch<'a>>,
+        params: Vec<CreateFlightPolicyParams>,
+    ) -> Result<()> {
+        instructions::create_flight_policies_batch::handler(ctx, params)
+    }
+
+    pub fn settle_flights_batch<'a>(
+        ctx: Context<'_, '_, 'a, 'a, SettleFlightsBatch<'a>>,
+        flight_count: u8,
+    ) -> Result<()> {
+        instructions::settle_flights_batch::handler(ctx, flight_count)
+    }
+
+    pub fn deposit_pool_capital(ctx: Context<DepositPoolCapital>, amount: u64) -> Result<()> {
+        instructions::deposit_pool_capital::handler(ctx, amount)
+    }
+
+    pub fn withdraw_pool_excess(ctx: Context<WithdrawPoolExcess>, amount: u64) -> Result<()> {
+        instructions::withdraw_pool_excess::handler(ctx, amount)
+    }
+
+    pub fn initialize_protocol_config(
+        ctx: Context<InitializeProtocolConfig>,
+        params: ProtocolConfigParams,
+    ) -> Result<()> {
+        instructions::protocol_config::initialize_handler(ctx, params)
+    }
+
+    pub fn update_protocol_config(
+        ctx: Context<UpdateProtocolConfig>,
+        params: ProtocolConfigParams,
+    ) -> Result<()> {
+        instructions::protocol_config::update_handler(ctx, params)
+    }
+
+    pub fn set_protocol_paused(ctx: Context<UpdateProtocolConfig>, paused: bool) -> Result<()> {
+        instructions::protocol_config::set_paused_handler(ctx, paused)
+    }
+
+    pub fn propose_protocol_admin(
+        ctx: Context<ProposeProtocolAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::protocol_admin::propose_handler(ctx, new_admin)
+    }
+
+    pub fn accept_protocol_admin(ctx: Context<AcceptProtocolAdmin>) -> Result<()> {
+        instructions::protocol_admin::accept_handler(ctx)
+    }
+
+    pub fn grant_master_role(
+        ctx: Context<ManageMasterRoles>,
+        member: Pubkey,
+        role: u8,
+    ) -> Result<()> {
+        instructions::manage_master_roles::grant_handler(ctx, member, role)
+    }
+
+    pub fn revoke_master_role(
+        ctx: Context<ManageMasterRoles>,
+        member: Pubkey,
+        role: u8,
+    ) -> Result<()> {
+        instructions::manage_master_roles::revoke_handler(ctx, member, role)
+    }
+
+    pub fn propose_master_authority(
+        ctx: Context<ProposeMasterAuthority>,
+        role: u8,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_master_authority::propose_handler(ctx, role, new_authority)
+    }
+
+    pub fn accept_master_authority(ctx: Context<AcceptMasterAuthority>, role: u8) -> Result<()> {
+        instructions::transfer_master_authority::accept_handler(ctx, role)
+    }
+
+    pub fn transfer_participant_share(
+        ctx: Context<TransferParticipantShare>,
+        share_bps: u16,
+    ) -> Result<()> {
+        instructions::transfer_participant_share::handler(ctx, share_bps)
+    }
+
+    pub fn propose_master_amendment(
+        ctx: Context<ProposeMasterAmendment>,
+        params: MasterAmendmentParams,
+    ) -> Result<()> {
+        instructions::master_amendment::propose_handler(ctx, params)
+    }
+
+    pub fn approve_master_amendment(ctx: Context<ApproveMasterAmendment>) -> Result<()> {
+        instructions::master_amendment::approve_handler(ctx)
+    }
+
+    pub fn apply_master_amendment(ctx: Context<ApplyMasterAmendment>) -> Result<()> {
+        instructions::master_amendment::apply_handler(ctx)
+    }
+
+    pub fn renew_master_policy<'a>(
+        ctx: Context<'_, '_, 'a, 'a, RenewMasterPolicy<'a>>,
+        params: RenewMasterPolicyParams,
+    ) -> Result<()> {
+        instructions::renew_master_policy::handler(ctx, params)
+    }
 }
@@ -1,15 +1,9 @@
+use crate::constants::MAX_PAYOUT_TIERS;
 use crate::errors::OpenParamError;
+use crate::state::PayoutTier;
 
 pub const BPS_DENOM: u64 = 10_000;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
-pub struct TierPayouts {
-    pub delay_2h: u64,
-    pub delay_3h: u64,
-    pub delay_4to5h: u64,
-    pub delay_6h_or_cancelled: u64,
-}
-
 pub fn effective_reinsurer_bps(
     ceded_ratio_bps: u16,
     commission_bps: u16,
@@ -28,20 +22,90 @@ pub fn effective_reinsurer_bps(
     u16::try_from(eff).map_err(|_| OpenParamError::MathOverflow)
 }
 
-pub fn tiered_payout(delay_minutes: u16, cancelled: bool, tiers: TierPayouts) -> u64 {
-    if cancelled || delay_minutes >= 360 {
-        return tiers.delay_6h_or_cancelled;
+pub fn validate_payout_tiers(tiers: &[PayoutTier]) -> Result<(), OpenParamError> {
+    if tiers.is_empty() || tiers.len() > MAX_PAYOUT_TIERS {
+        return Err(OpenParamError::InvalidInput);
     }
-    if (240..360).contains(&delay_minutes) {
-        return tiers.delay_4to5h;
+    let mut prev: Option<u16> = None;
+    for tier in tiers {
+        if tier.payout == 0 {
+            return Err(OpenParamError::InvalidPayout);
+        }
+        if prev.is_some_and(|p| tier.min_delay_minutes <= p) {
+            return Err(OpenParamError::InvalidDelayThreshold);
+        }
+        prev = Some(tier.min_delay_minutes);
     }
-    if (180..240).contains(&delay_minutes) {
-        return tiers.delay_3h;
+    Ok(())
+}
+
+pub fn tiered_payout(
+    delay_minutes: u16,
+    cancelled: bool,
+    tiers: &[PayoutTier],
+    cancellation_payout: u64,
+) -> u64 {
+    if cancelled {
+        return cancellation_payout;
     }
-    if (120..180).contains(&delay_minutes) {
-        return tiers.delay_2h;
+    // 임계치 오름차순 테이블에서 지연 분 이하인 마지막 구간을 적용한다.
+    tiers
+        .iter()
+        .take_while(|t| t.min_delay_minutes <= delay_minutes)
+        .last()
+        .map_or(0, |t| t.payout)
+}
+
+pub fn max_policy_liability(tiers: &[PayoutTier], cancellation_payout: u64) -> u64 {
+    // 지연 구간 최대 지급액과 결항 지급액 중 큰 값이 정책 1건의 최대 부담이다.
+    tiers
+        .iter()
+        .map(|t| t.payout)
+        .fold(cancellation_payout, u64::max)
+}
+
+pub fn xol_recovery(loss: u64, retention: u64, limit: u64) -> u64 {
+    // retention 초과분을 limit 한도 내에서 XoL 재보험사가 부담한다.
+    loss.saturating_sub(retention).min(limit)
+}
+
+pub fn xol_event_recovery(
+    gross_loss_before: u64,
+    recovered_before: u64,
+    payout: u64,
+    retention: u64,
+    limit: u64,
+) -> Result<u64, OpenParamError> {
+    // 같은 사고의 누적 손해에 retention/limit을 적용하고, 이미 회수한 금액을 뺀 만큼을 이번 청구에서 회수한다.
+    let gross_after = gross_loss_before
+        .checked_add(payout)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let due = xol_recovery(gross_after, retention, limit);
+    Ok(due.saturating_sub(recovered_before).min(payout))
+}
+
+pub fn stop_loss_recovery(
+    claims_paid_before: u64,
+    payout: u64,
+    premium_base: u64,
+    attachment_bps: u16,
+    limit: u64,
+    recovered: u64,
+) -> Result<u64, OpenParamError> {
+    // 누적 지급 보험금 중 보험료 기준액 × attachment 초과분(누적 limit 한도)에서
+    // 이미 회수한 금액을 뺀 만큼을 이번 청구에서 회수한다.
+    if limit == 0 {
+        return Ok(0);
     }
-    0
+    let attachment = premium_base
+        .checked_mul(attachment_bps as u64)
+        .ok_or(OpenParamError::MathOverflow)?
+        / BPS_DENOM;
+    let claims_after = claims_paid_before
+        .checked_add(payout)
+        .ok_or(OpenParamError::MathOverflow)?;
+    let due = claims_after.saturating_sub(attachment).min(limit);
+    Ok(due.saturating_sub(recovered).min(payout))
 }
 
 pub fn split_by_bps(total: u64, ratios_bps: &[u16]) -> Result<Vec<u64>, OpenParamError> {
@@ -81,15 +145,17 @@ pub fn split_by_bps(total: u64, ratios_bps: &[u16]) -> Result<Vec<u64>, OpenPara
 mod tests {
     use super::*;
 
-    fn tiers() -> TierPayouts {
-        TierPayouts {
-            delay_2h: 40,
-            delay_3h: 60,
-            delay_4to5h: 80,
-            delay_6h_or_cancelled: 100,
+    fn tier(min_delay_minutes: u16, payout: u64) -> PayoutTier {
+        PayoutTier {
+            min_delay_minutes,
+            payout,
         }
     }
 
+    fn tiers() -> Vec<PayoutTier> {
+        vec![tier(120, 40), tier(180, 60), tier(240, 80), tier(360, 100)]
+    }
+
     #[test]
     fn effective_reinsurer_ratio_is_45pct_for_50pct_ceded_with_10pct_commission() {
         let eff = effective_reinsurer_bps(5_000, 1_000).unwrap();
@@ -98,12 +164,106 @@ mod tests {
 
     #[test]
     fn payout_tiers_work() {
-        assert_eq!(tiered_payout(119, false, tiers()), 0);
-        assert_eq!(tiered_payout(120, false, tiers()), 40);
-        assert_eq!(tiered_payout(180, false, tiers()), 60);
-        assert_eq!(tiered_payout(240, false, tiers()), 80);
-        assert_eq!(tiered_payout(360, false, tiers()), 100);
-        assert_eq!(tiered_payout(10, true, tiers()), 100);
+        assert_eq!(tiered_payout(119, false, &tiers(), 100), 0);
+        assert_eq!(tiered_payout(120, false, &tiers(), 100), 40);
+        assert_eq!(tiered_payout(180, false, &tiers(), 100), 60);
+        assert_eq!(tiered_payout(240, false, &tiers(), 100), 80);
+        assert_eq!(tiered_payout(360, false, &tiers(), 100), 100);
+        assert_eq!(tiered_payout(10, true, &tiers(), 100), 100);
+    }
+
+    #[test]
+    fn max_liability_is_largest_of_tiers_and_cancellation() {
+        assert_eq!(max_policy_liability(&tiers(), 100), 100);
+        assert_eq!(max_policy_liability(&tiers(), 150), 150);
+        assert_eq!(max_policy_liability(&[tier(60, 10), tier(90, 70)], 50), 70);
+    }
+
+    #[test]
+    fn xol_covers_layer_above_retention_up_to_limit() {
+        assert_eq!(xol_recovery(50, 60, 100), 0);
+        assert_eq!(xol_recovery(80, 60, 100), 20);
+        assert_eq!(xol_recovery(500, 60, 100), 100);
+        assert_eq!(xol_recovery(500, 60, 0), 0);
+    }
+
+    #[test]
+    fn xol_applies_retention_and_limit_to_event_running_total() {
+        // retention 60, limit 100: 같은 항공편 청구 40 × 5건이면 누적 손해 기준으로 0, 20, 40, 40, 0을 회수한다.
+        let mut gross = 0;
+        let mut recovered = 0;
+        let mut recoveries = vec![];
+        for _ in 0..5 {
+            let r = xol_event_recovery(gross, recovered, 40, 60, 100).unwrap();
+            gross += 40;
+            recovered += r;
+            recoveries.push(r);
+        }
+        assert_eq!(recoveries, vec![0, 20, 40, 40, 0]);
+        assert_eq!(recovered, 100);
+        // 청구 순서를 바꿔도 사고 단위 총 회수액은 같다.
+        let first = xol_event_recovery(0, 0, 200, 60, 100).unwrap();
+        assert_eq!(first, 100);
+        assert_eq!(xol_event_recovery(200, 100, 40, 60, 100).unwrap(), 0);
+        assert_eq!(xol_event_recovery(0, 0, 40, 60, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn stop_loss_recovers_claims_above_attachment_up_to_limit() {
+        // 보험료 기준액 1000, attachment 80% → 누적 지급 800 초과분부터 회수.
+        assert_eq!(
+            stop_loss_recovery(700, 80, 1_000, 8_000, 500, 0).unwrap(),
+            0
+        );
+        assert_eq!(
+            stop_loss_recovery(760, 80, 1_000, 8_000, 500, 0).unwrap(),
+            40
+        );
+        assert_eq!(
+            stop_loss_recovery(840, 80, 1_000, 8_000, 500, 40).unwrap(),
+            80
+        );
+        // 누적 한도 500 중 480을 이미 회수했다면 20만 남는다.
+        assert_eq!(
+            stop_loss_recovery(1_800, 80, 1_000, 8_000, 500, 480).unwrap(),
+            20
+        );
+        assert_eq!(
+            stop_loss_recovery(1_800, 80, 1_000, 8_000, 0, 0).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn custom_tiers_and_separate_cancellation_payout() {
+        // 단거리 상품: 60/90/150분 구간, 결항은 별도 지급액.
+        let short_haul = vec![tier(60, 10), tier(90, 20), tier(150, 30)];
+        assert_eq!(tiered_payout(59, false, &short_haul, 50), 0);
+        assert_eq!(tiered_payout(60, false, &short_haul, 50), 10);
+        assert_eq!(tiered_payout(149, false, &short_haul, 50), 20);
+        assert_eq!(tiered_payout(600, false, &short_haul, 50), 30);
+        assert_eq!(tiered_payout(0, true, &short_haul, 50), 50);
+    }
+
+    #[test]
+    fn payout_tiers_require_increasing_thresholds() {
+        assert!(validate_payout_tiers(&tiers()).is_ok());
+        assert!(matches!(
+            validate_payout_tiers(&[]),
+            Err(OpenParamError::InvalidInput)
+        ));
+        assert!(matches!(
+            validate_payout_tiers(&[tier(120, 40), tier(120, 60)]),
+            Err(OpenParamError::InvalidDelayThreshold)
+        ));
+        assert!(matches!(
+            validate_payout_tiers(&[tier(180, 60), tier(120, 40)]),
+            Err(OpenParamError::InvalidDelayThreshold)
+        ));
+        assert!(matches!(
+            validate_payout_tiers(&[tier(120, 0)]),
+            Err(OpenParamError::InvalidPayout)
+        ));
     }
 
     #[test]
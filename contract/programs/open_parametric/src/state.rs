@@ -175,6 +175,46 @@ pub enum FlightPolicyStatus {
     Paid = 3,
     NoClaim = 4,
     Expired = 5,
+    Voided = 6,
+    Disputed = 7,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ExpiryAction {
+    DistributeAsNoClaim = 0,
+    Refund = 1,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MasterAuthority {
+    Leader = 0,
+    Operator = 1,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum MasterRole {
+    // child 정책 발급(단건/배치).
+    Issuer = 0,
+    // 서명 기반 지연 결과 확정.
+    Resolver = 1,
+    // 청구/무청구 정산(단건/배치).
+    Settler = 2,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DeductionTiming {
+    // 발급 시 납입 보험료에서 수수료/세금을 떼어 treasury로 보낸다.
+    AtIssuance = 0,
+    // 무청구 정산 시 리더 deposit의 보험료에서 떼어 treasury로 보낸다.
+    AtNoClaimSettlement = 1,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum CommissionMode {
+    // 출재 수수료를 재보험 실효 지분율에 녹여 보험료/보험금 모두 실효 비율로 분할한다.
+    Legacy = 0,
+    // 보험료/보험금은 출재율 그대로 분할하고 출재 수수료를 별도 금액으로 돌려받는다.
+    Explicit = 1,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -183,6 +223,12 @@ pub enum ConfirmRole {
     Reinsurer = 1,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayoutTier {
+    pub min_delay_minutes: u16,
+    pub payout: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct MasterParticipantInit {
     pub insurer: Pubkey,
@@ -196,6 +242,28 @@ pub struct MasterParticipant {
     pub confirmed: bool,
     pub pool_wallet: Pubkey,
     pub deposit_wallet: Pubkey,
+    // deposit_pool_capital/withdraw_pool_excess 누적 금액.
+    pub capital_deposited: u64,
+    pub capital_withdrawn: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProtocolConfigParams {
+    pub allowed_mints: Vec<Pubkey>,
+    pub allowed_queues: Vec<Pubkey>,
+    pub oracle_max_staleness_slots: u64,
+    pub delay_threshold_min: u16,
+    pub protocol_fee_bps: u16,
+    pub premium_tax_bps: u16,
+    pub fee_treasury: Pubkey,
+    pub tax_treasury: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RoleGrant {
+    pub member: Pubkey,
+    // MasterRole 값별 비트(1 << role) 조합.
+    pub roles: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -204,13 +272,60 @@ pub struct CreateMasterPolicyParams {
     pub coverage_start_ts: i64,
     pub coverage_end_ts: i64,
     pub premium_per_policy: u64,
-    pub payout_delay_2h: u64,
-    pub payout_delay_3h: u64,
-    pub payout_delay_4to5h: u64,
-    pub payout_delay_6h_or_cancelled: u64,
+    pub payout_tiers: Vec<PayoutTier>,
+    pub cancellation_payout: u64,
     pub ceded_ratio_bps: u16,
     pub reins_commission_bps: u16,
     pub participants: Vec<MasterParticipantInit>,
+    pub resolution_timeout_hours: u16,
+    pub expiry_action: u8,
+    pub oracle_only_resolution: bool,
+    pub resolvers: Vec<Pubkey>,
+    pub resolver_threshold: u8,
+    pub challenge_period_secs: i64,
+    pub min_lead_time_secs: i64,
+    pub aggregate_liability_limit: u64,
+    pub max_policy_count: u64,
+    pub pool_margin_bps: u16,
+    pub xol_reinsurer: Pubkey,
+    pub xol_pool_wallet: Pubkey,
+    pub xol_retention: u64,
+    pub xol_limit: u64,
+    pub stop_loss_reinsurer: Pubkey,
+    pub stop_loss_pool_wallet: Pubkey,
+    pub stop_loss_attachment_bps: u16,
+    pub stop_loss_limit: u64,
+    pub commission_mode: u8,
+    pub deduction_timing: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MasterAmendmentParams {
+    pub amendment_id: u64,
+    pub premium_per_policy: u64,
+    pub payout_tiers: Vec<PayoutTier>,
+    pub cancellation_payout: u64,
+    pub ceded_ratio_bps: u16,
+    pub reins_commission_bps: u16,
+    pub coverage_end_ts: i64,
+}
+
+// None인 항목은 이전 마스터 약관을 그대로 이어받는다.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RenewMasterPolicyParams {
+    pub master_id: u64,
+    pub coverage_start_ts: i64,
+    pub coverage_end_ts: i64,
+    pub premium_per_policy: Option<u64>,
+    pub payout_tiers: Option<Vec<PayoutTier>>,
+    pub cancellation_payout: Option<u64>,
+    pub ceded_ratio_bps: Option<u16>,
+    pub reins_commission_bps: Option<u16>,
+    pub aggregate_liability_limit: Option<u64>,
+    pub max_policy_count: Option<u64>,
+    // 레이어 풀 지갑은 새 마스터 PDA 소유여야 하므로 레이어 사용 시 새로 지정한다.
+    pub xol_pool_wallet: Pubkey,
+    pub stop_loss_pool_wallet: Pubkey,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -227,6 +342,10 @@ pub struct CreateFlightPolicyParams {
     pub flight_no: String,
     pub route: String,
     pub departure_ts: i64,
+    pub delay_feed_id: [u8; 32],
+    pub cancel_feed_id: [u8; 32],
+    // Pubkey::default()면 리더 deposit으로 수취만 하고 직접 지급하지 않는다.
+    pub beneficiary_token: Pubkey,
 }
 
 #[account]
@@ -238,10 +357,8 @@ pub struct MasterPolicy {
     pub coverage_start_ts: i64,
     pub coverage_end_ts: i64,
     pub premium_per_policy: u64,
-    pub payout_delay_2h: u64,
-    pub payout_delay_3h: u64,
-    pub payout_delay_4to5h: u64,
-    pub payout_delay_6h_or_cancelled: u64,
+    pub payout_tiers: Vec<PayoutTier>,
+    pub cancellation_payout: u64,
     pub ceded_ratio_bps: u16,
     pub reins_commission_bps: u16,
     pub reinsurer_effective_bps: u16,
@@ -254,6 +371,81 @@ pub struct MasterPolicy {
     pub status: u8,
     pub created_at: i64,
     pub bump: u8,
+    // 누적 집계: 발급/미정산 건수와 보험료·보험금 합계.
+    pub issued_policy_count: u64,
+    pub open_policy_count: u64,
+    pub total_premium_collected: u64,
+    pub total_claims_paid: u64,
+    // Closed/Cancelled 전환 시각.
+    pub closed_at: i64,
+    // 출발 후 결과 미확정 상태로 이 시간이 지나면 누구나 만료 처리할 수 있다.
+    pub resolution_timeout_hours: u16,
+    pub expiry_action: u8,
+    // true면 Switchboard 검증 quote로만 지연 결과를 확정할 수 있다.
+    pub oracle_only_resolution: bool,
+    // 비어 있지 않으면 resolver M-of-N 합의로만 서명 기반 결과를 확정한다.
+    pub resolvers: Vec<Pubkey>,
+    pub resolver_threshold: u8,
+    // 결과 확정 후 이의 제기가 가능한 기간(초). 기간 중에는 정산할 수 없다.
+    pub challenge_period_secs: i64,
+    // 출발 시각까지 남은 시간이 이보다 짧으면 child 정책을 발급하지 않는다.
+    pub min_lead_time_secs: i64,
+    // 미정산 child 정책의 최대 지급 가능액 합계와 그 한도.
+    pub outstanding_liability: u64,
+    pub aggregate_liability_limit: u64,
+    // 미정산 child 정책별 최대 지급 가능액 × 발급 시점 출재 비율의 합계(재보험 풀 예약 몫).
+    pub ceded_outstanding_liability: u64,
+    // issued_policy_count가 넘을 수 없는 발급 건수 한도.
+    pub max_policy_count: u64,
+    // 풀 지갑 인출 시 예약 부담액 몫 위에 추가로 남겨야 하는 여유분 비율.
+    pub pool_margin_bps: u16,
+    pub reinsurer_capital_deposited: u64,
+    pub reinsurer_capital_withdrawn: u64,
+    // 항공편(child 청구) 단위 초과손해(XoL) 레이어. xol_limit이 0이면 미사용.
+    pub xol_reinsurer: Pubkey,
+    pub xol_pool_wallet: Pubkey,
+    pub xol_retention: u64,
+    pub xol_limit: u64,
+    pub xol_capital_deposited: u64,
+    pub xol_capital_withdrawn: u64,
+    // 마스터 전체 손해율(발생 손해 / 경과 보험료)이 attachment를 넘는 청구분을
+    // stop_loss_limit 누적 한도까지 stop-loss 재보험사가 부담한다. limit이 0이면 미사용.
+    pub stop_loss_reinsurer: Pubkey,
+    pub stop_loss_pool_wallet: Pubkey,
+    pub stop_loss_attachment_bps: u16,
+    pub stop_loss_limit: u64,
+    pub stop_loss_recovered: u64,
+    pub stop_loss_capital_deposited: u64,
+    pub stop_loss_capital_withdrawn: u64,
+    // 청구/무청구로 정산 완료된 child 정책의 보험료 합계.
+    pub earned_premium: u64,
+    // 청구 정산된 child 정책의 payout 합계(stop-loss 손해율 분자).
+    pub incurred_claims: u64,
+    pub commission_mode: u8,
+    // 보험료에서 떼어 treasury로 보내는 프로토콜 수수료/보험료 세금 비율과 수취 토큰 계정.
+    // 비율은 마스터 생성 시점의 ProtocolConfig 값, 토큰 계정은 config treasury 소유의 통화 mint 계정이다.
+    pub protocol_fee_bps: u16,
+    pub premium_tax_bps: u16,
+    pub fee_treasury_token: Pubkey,
+    pub tax_treasury_token: Pubkey,
+    pub deduction_timing: u8,
+    pub total_protocol_fees: u64,
+    pub total_premium_tax: u64,
+    // 정산 완료 기준 출재 보험료(수수료 차감 전), 출재 수수료, 재보험사 부담 보험금 누적액.
+    pub total_ceded_premium: u64,
+    pub total_ceding_commission: u64,
+    pub total_ceded_claims: u64,
+    // leader/operator 외에 역할별 권한을 부여받은 계정 목록.
+    pub role_grants: Vec<RoleGrant>,
+    // PDA 서명 seed에 쓰는 최초 생성 리더. leader가 교체되어도 바뀌지 않는다.
+    pub seed_leader: Pubkey,
+    // 수락 대기 중인 leader/operator 인계 대상. 없으면 Pubkey::default().
+    pub pending_leader: Pubkey,
+    pub pending_operator: Pubkey,
+    // apply_master_amendment마다 1씩 증가하는 약관 버전(생성 시 1).
+    pub terms_version: u32,
+    // renew_master_policy로 만든 경우 이전 마스터 계정. 없으면 Pubkey::default().
+    pub predecessor: Pubkey,
 }
 
 #[account]
@@ -274,4 +466,100 @@ pub struct FlightPolicy {
     pub created_at: i64,
     pub updated_at: i64,
     pub bump: u8,
+    // 보험료를 납입한 토큰 계정(무효화 시 환급 대상).
+    pub payer_token: Pubkey,
+    // 이 항공편 결과를 제공하는 Switchboard feed id(지연 분/결항 여부).
+    pub delay_feed_id: [u8; 32],
+    pub cancel_feed_id: [u8; 32],
+    // 이 시각 이전에는 이의 제기만 가능하고 정산은 불가하다.
+    pub challenge_deadline: i64,
+    pub disputed_by: Pubkey,
+    pub disputed_at: i64,
+    // 분쟁 직전 상태. 재확정 없이 분쟁 대기 시간이 지나면 이 상태로 되돌린다.
+    pub pre_dispute_status: u8,
+    // 설정된 경우 청구 정산 시 payout_amount를 이 토큰 계정으로 지급한다.
+    pub beneficiary_token: Pubkey,
+    // 발급 시점 기준 최대 지급 가능액. 종결 시 outstanding_liability에서 이 값을 뺀다.
+    pub max_liability: u64,
+    // treasury로 보낸 프로토콜 수수료/보험료 세금. 차감 시점 전에는 0이다.
+    pub protocol_fee_paid: u64,
+    pub premium_tax_paid: u64,
+    // 발급 시점 마스터 약관 버전과 결과 확정/정산에 쓰는 약관 스냅샷.
+    // 이후 약관 변경(amendment)은 이미 발급된 정책에 적용되지 않는다.
+    pub terms_version: u32,
+    pub payout_tiers: Vec<PayoutTier>,
+    pub cancellation_payout: u64,
+    pub ceded_ratio_bps: u16,
+    pub reins_commission_bps: u16,
+    pub reinsurer_effective_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct ResolverObservation {
+    pub resolver: Pubkey,
+    pub delay_minutes: u16,
+    pub cancelled: bool,
+    pub submitted_at: i64,
+}
+
+#[account]
+pub struct FlightResolution {
+    pub flight_policy: Pubkey,
+    pub master: Pubkey,
+    pub observations: Vec<ResolverObservation>,
+    pub finalized: bool,
+    pub bump: u8,
+}
+
+// XoL retention/limit을 항공편(편명+출발 시각) 단위 사고로 누적 적용하기 위한 집계.
+#[account]
+pub struct XolEventLoss {
+    pub master: Pubkey,
+    pub flight_no: String,
+    pub departure_ts: i64,
+    // 이 사고로 정산된 child 청구 보험금 합계와 그중 XoL 레이어가 부담한 금액.
+    pub gross_loss: u64,
+    pub recovered: u64,
+    pub bump: u8,
+}
+
+#[account]
+pub struct ProtocolConfig {
+    pub admin: Pubkey,
+    // propose 후 accept 전까지의 새 admin 후보. 없으면 Pubkey::default().
+    pub pending_admin: Pubkey,
+    // 마스터/레거시 정책 생성에 쓸 수 있는 통화 mint와 Switchboard 오라클 queue.
+    pub allowed_mints: Vec<Pubkey>,
+    pub allowed_queues: Vec<Pubkey>,
+    pub oracle_max_staleness_slots: u64,
+    pub delay_threshold_min: u16,
+    // 신규 마스터에 스냅샷되는 프로토콜 수수료/보험료 세금 설정.
+    // treasury는 수취 토큰 계정의 소유자이며, 통화 mint별 토큰 계정은 마스터 생성 시 지정한다.
+    pub protocol_fee_bps: u16,
+    pub premium_tax_bps: u16,
+    pub fee_treasury: Pubkey,
+    pub tax_treasury: Pubkey,
+    // true면 신규 정책 생성/발급을 막는다(정산은 계속 가능).
+    pub paused: bool,
+    pub bump: u8,
+}
+
+#[account]
+pub struct MasterAmendment {
+    pub master: Pubkey,
+    pub amendment_id: u64,
+    // 제안 시점 마스터 약관 버전. 그 사이 다른 변경이 적용되면 이 제안은 적용할 수 없다.
+    pub base_terms_version: u32,
+    pub proposer: Pubkey,
+    pub premium_per_policy: u64,
+    pub payout_tiers: Vec<PayoutTier>,
+    pub cancellation_payout: u64,
+    pub ceded_ratio_bps: u16,
+    pub reins_commission_bps: u16,
+    pub coverage_end_ts: i64,
+    // 승인한 참여사/재보험사 계정.
+    pub approvals: Vec<Pubkey>,
+    pub applied: bool,
+    pub created_at: i64,
+    pub bump: u8,
 }
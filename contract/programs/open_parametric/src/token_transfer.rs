@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensions, StateWithExtensions,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_interface::{self, Mint, TransferChecked};
+
+use crate::errors::OpenParamError;
+
+/// SPL Token/Token-2022 공통 transfer_checked 이체. 수취 계정에 실제로 입금된 금액을 돌려준다.
+pub fn transfer_checked_received<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            TransferChecked {
+                from,
+                mint: mint.to_account_info(),
+                to,
+                authority,
+            },
+            signer,
+        ),
+        amount,
+        mint.decimals,
+    )?;
+    let mint_info = mint.to_account_info();
+    let fee = transfer_fee(&mint_info.try_borrow_data()?, Clock::get()?.epoch, amount)?;
+    Ok(amount - fee)
+}
+
+pub(crate) fn transfer_fee(
+    mint_data: &[u8],
+    epoch: u64,
+    amount: u64,
+) -> std::result::Result<u64, OpenParamError> {
+    // transfer-fee 확장이 없는 mint(레거시 SPL Token 포함)는 수수료 없이 전액 입금된다.
+    let mint = StateWithExtensions::<MintState>::unpack(mint_data)
+        .map_err(|_| OpenParamError::InvalidInput)?;
+    let Ok(fee_config) = mint.get_extension::<TransferFeeConfig>() else {
+        return Ok(0);
+    };
+    fee_config
+        .calculate_epoch_fee(epoch, amount)
+        .ok_or(OpenParamError::MathOverflow)
+}
@@ -0,0 +1,66 @@
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token_2022::spl_token_2022::extension::transfer_fee::{
+    TransferFee, TransferFeeConfig,
+};
+use anchor_spl::token_2022::spl_token_2022::extension::{
+    BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+};
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+
+use crate::errors::OpenParamError;
+
+use super::token_transfer::transfer_fee;
+
+fn mint_data(fee: Option<(u16, u64)>) -> Vec<u8> {
+    let base = MintState {
+        decimals: 6,
+        is_initialized: true,
+        ..MintState::default()
+    };
+    let Some((fee_bps, maximum_fee)) = fee else {
+        let mut data = vec![0u8; MintState::LEN];
+        MintState::pack(base, &mut data).unwrap();
+        return data;
+    };
+    let len =
+        ExtensionType::try_calculate_account_len::<MintState>(&[ExtensionType::TransferFeeConfig])
+            .unwrap();
+    let mut data = vec![0u8; len];
+    let mut state = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut data).unwrap();
+    let config = state.init_extension::<TransferFeeConfig>(true).unwrap();
+    let transfer_fee = TransferFee {
+        epoch: 0.into(),
+        maximum_fee: maximum_fee.into(),
+        transfer_fee_basis_points: fee_bps.into(),
+    };
+    config.older_transfer_fee = transfer_fee;
+    config.newer_transfer_fee = transfer_fee;
+    state.base = base;
+    state.pack_base();
+    state.init_account_type().unwrap();
+    data
+}
+
+#[test]
+fn legacy_mint_has_no_transfer_fee() {
+    // 확장이 없는 mint는 이체 금액 전액이 입금된다.
+    assert_eq!(transfer_fee(&mint_data(None), 10, 1_000_000).unwrap(), 0);
+}
+
+#[test]
+fn transfer_fee_extension_is_deducted_up_to_maximum() {
+    // 1%(100bps) 수수료는 올림 계산되며 maximum_fee를 넘지 않는다.
+    let data = mint_data(Some((100, 5_000)));
+    assert_eq!(transfer_fee(&data, 10, 100_000).unwrap(), 1_000);
+    assert_eq!(transfer_fee(&data, 10, 150).unwrap(), 2);
+    assert_eq!(transfer_fee(&data, 10, 10_000_000).unwrap(), 5_000);
+}
+
+#[test]
+fn transfer_fee_rejects_non_mint_data() {
+    // mint 형식이 아닌 데이터는 실패한다.
+    assert!(matches!(
+        transfer_fee(&[0u8; 10], 10, 1_000),
+        Err(OpenParamError::InvalidInput)
+    ));
+}
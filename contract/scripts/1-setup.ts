@@ -67,7 +67,7 @@ async function main() {
 
   console.log("\n=== Setup 완료 ===");
   console.log(`activeTo: ${new Date(activeTo * 1000).toLocaleTimeString()} (2분 후)`);
-  console.log("다음 단계: anchor run create-policy");
+  console.log("다음 단계: anchor run init-config → anchor run create-policy");
 }
 
 main().catch(e => { console.error(e); process.exit(1); });
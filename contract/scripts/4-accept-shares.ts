@@ -38,6 +38,7 @@ async function main() {
       participant:    ins1.publicKey,
       policy, underwriting: uw, riskPool: pool,
       participantToken: ins1Token, vault,
+      currencyMint:   mint,
       tokenProgram:   TOKEN_PROGRAM_ID,
     })
     .signers([ins1])
@@ -53,6 +54,7 @@ async function main() {
       participant:    ins2.publicKey,
       policy, underwriting: uw, riskPool: pool,
       participantToken: ins2Token, vault,
+      currencyMint:   mint,
       tokenProgram:   TOKEN_PROGRAM_ID,
     })
     .signers([ins2])
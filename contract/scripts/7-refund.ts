@@ -50,6 +50,7 @@ async function main() {
       vault,
       participantToken: ins1Token,
       underwriting:     uw,
+      currencyMint:     mint,
       tokenProgram:     TOKEN_PROGRAM_ID,
     })
     .signers([ins1])
@@ -67,6 +68,7 @@ async function main() {
       vault,
       participantToken: ins2Token,
       underwriting:     uw,
+      currencyMint:     mint,
       tokenProgram:     TOKEN_PROGRAM_ID,
     })
     .signers([ins2])
@@ -0,0 +1,56 @@
+/**
+ * anchor run init-config
+ *
+ * 프로그램 전역 ProtocolConfig를 생성하거나, 이미 있으면 setup에서 만든 민트를 허용 목록에 추가합니다.
+ * 최초 생성 시 leader가 admin이 되며, 이후 변경은 admin 서명으로만 가능합니다.
+ */
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { ON_DEMAND_DEVNET_QUEUE } from "@switchboard-xyz/on-demand";
+import { loadState, kp, makeProgram, PROGRAM_ID } from "./common";
+
+async function main() {
+  const s      = loadState();
+  const leader = kp(s.leaderKey);
+  const mint   = new PublicKey(s.mint);
+  const pg     = makeProgram(leader);
+
+  const [config] = PublicKey.findProgramAddressSync([Buffer.from("protocol_config")], PROGRAM_ID);
+  const existing = await pg.account.protocolConfig.fetchNullable(config);
+
+  const params = {
+    allowedMints:            [...(existing?.allowedMints ?? []), mint].slice(-8),
+    allowedQueues:           [new PublicKey(ON_DEMAND_DEVNET_QUEUE)],
+    oracleMaxStalenessSlots: new BN(150),
+    delayThresholdMin:       120,
+    protocolFeeBps:          0,
+    premiumTaxBps:           0,
+    feeTreasuryToken:        PublicKey.default,
+    taxTreasuryToken:        PublicKey.default,
+  };
+
+  if (!existing) {
+    const tx = await pg.methods
+      .initializeProtocolConfig(params)
+      .accountsPartial({ admin: leader.publicKey, protocolConfig: config, systemProgram: SystemProgram.programId })
+      .signers([leader])
+      .rpc();
+    console.log("ProtocolConfig 생성 tx:", tx);
+  } else if (existing.admin.equals(leader.publicKey)) {
+    const tx = await pg.methods
+      .updateProtocolConfig(params)
+      .accountsPartial({ admin: leader.publicKey, protocolConfig: config })
+      .signers([leader])
+      .rpc();
+    console.log("ProtocolConfig 갱신 tx:", tx);
+  } else {
+    throw new Error(
+      `ProtocolConfig admin(${existing.admin.toBase58()})만 민트를 추가할 수 있습니다.`
+    );
+  }
+
+  console.log("ProtocolConfig:", config.toBase58());
+  console.log("허용 민트     :", mint.toBase58());
+}
+
+main().catch(e => { console.error(e); process.exit(1); });
@@ -30,6 +30,48 @@ describe("settle_flight_claim", () => {
   const aShare = 13_200_000n; // 44 * 0.3
   const bShare = 8_800_000n; // 44 * 0.2
 
+  const [protocolConfigPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("protocol_config")],
+    program.programId
+  );
+  // 전역 설정 초기화는 프로그램 업그레이드 권한자(로컬 배포 시 provider 지갑)만 할 수 있다.
+  const [programDataPda] = PublicKey.findProgramAddressSync(
+    [program.programId.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+
+  // 전역 설정이 없으면 생성하고, 있으면 테스트 mint를 허용 목록에 추가한다.
+  async function allowMint(mint: PublicKey): Promise<void> {
+    const existing = await program.account.protocolConfig.fetchNullable(protocolConfigPda);
+    const params = {
+      allowedMints: [...(existing?.allowedMints ?? []), mint].slice(-8),
+      allowedQueues: existing?.allowedQueues ?? [],
+      oracleMaxStalenessSlots: new anchor.BN(150),
+      delayThresholdMin: 120,
+      protocolFeeBps: 0,
+      premiumTaxBps: 0,
+      feeTreasury: PublicKey.default,
+      taxTreasury: PublicKey.default,
+    };
+    if (existing) {
+      await program.methods
+        .updateProtocolConfig(params)
+        .accounts({ admin: payer.publicKey, protocolConfig: protocolConfigPda })
+        .rpc();
+    } else {
+      await program.methods
+        .initializeProtocolConfig(params)
+        .accounts({
+          admin: payer.publicKey,
+          protocolConfig: protocolConfigPda,
+          program: program.programId,
+          programData: programDataPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+    }
+  }
+
   async function airdrop(pubkey: PublicKey, sol = 2): Promise<void> {
     const sig = await connection.requestAirdrop(pubkey, sol * LAMPORTS_PER_SOL);
     const latest = await connection.getLatestBlockhash();
@@ -52,6 +94,7 @@ describe("settle_flight_claim", () => {
       null,
       DECIMALS
     );
+    await allowMint(mint);
 
     const masterId = new anchor.BN(1);
     const [masterPolicyPda] = PublicKey.findProgramAddressSync(
@@ -90,10 +133,10 @@ describe("settle_flight_claim", () => {
         coverageStartTs: new anchor.BN(now - 10),
         coverageEndTs: new anchor.BN(now + 3600),
         premiumPerPolicy: new anchor.BN(premiumAmount.toString()),
-        payoutDelay2h: new anchor.BN(0),
-        payoutDelay3h: new anchor.BN(0),
-        payoutDelay4to5h: new anchor.BN(0),
-        payoutDelay6hOrCancelled: new anchor.BN(payoutAmount.toString()),
+        payoutTiers: [
+          { minDelayMinutes: 360, payout: new anchor.BN(payoutAmount.toString()) },
+        ],
+        cancellationPayout: new anchor.BN(payoutAmount.toString()),
         cededRatioBps: 5_000,
         reinsCommissionBps: 1_000,
         participants: [
@@ -101,16 +144,39 @@ describe("settle_flight_claim", () => {
           { insurer: participantA.publicKey, shareBps: 3_000 },
           { insurer: participantB.publicKey, shareBps: 2_000 },
         ],
+        resolutionTimeoutHours: 72,
+        expiryAction: 0,
+        oracleOnlyResolution: false,
+        resolvers: [],
+        resolverThreshold: 0,
+        challengePeriodSecs: new anchor.BN(0),
+        minLeadTimeSecs: new anchor.BN(300),
+        aggregateLiabilityLimit: new anchor.BN(payoutAmount.toString()),
+        maxPolicyCount: new anchor.BN(1),
+        poolMarginBps: 0,
+        xolReinsurer: PublicKey.default,
+        xolPoolWallet: PublicKey.default,
+        xolRetention: new anchor.BN(0),
+        xolLimit: new anchor.BN(0),
+        stopLossReinsurer: PublicKey.default,
+        stopLossPoolWallet: PublicKey.default,
+        stopLossAttachmentBps: 0,
+        stopLossLimit: new anchor.BN(0),
+        commissionMode: 0,
+        deductionTiming: 0,
       })
       .accounts({
         leader: payer.publicKey,
         operator: payer.publicKey,
         reinsurer: reinsurer.publicKey,
         currencyMint: mint,
+        protocolConfig: protocolConfigPda,
         masterPolicy: masterPolicyPda,
         leaderDepositWallet: leaderDeposit,
         reinsurerPoolWallet: reinsurerPool,
         reinsurerDepositWallet: reinsurerDeposit,
+        feeTreasuryToken: null,
+        taxTreasuryToken: null,
         systemProgram: SystemProgram.programId,
       })
       .rpc();
@@ -207,16 +273,31 @@ describe("settle_flight_claim", () => {
         flightNo: "AB123",
         route: "ICN-SFO",
         departureTs: new anchor.BN(now + 600),
+        delayFeedId: Array(32).fill(0),
+        cancelFeedId: Array(32).fill(0),
+        beneficiaryToken: PublicKey.default,
       })
       .accounts({
         creator: payer.publicKey,
+        protocolConfig: protocolConfigPda,
         masterPolicy: masterPolicyPda,
         flightPolicy: flightPolicyPda,
         payerToken: payerToken,
         leaderDepositToken: leaderDeposit,
+        reinsurerPoolToken: reinsurerPool,
+        xolPoolToken: null,
+        feeTreasuryToken: null,
+        taxTreasuryToken: null,
+        beneficiaryToken: null,
+        currencyMint: mint,
         tokenProgram: TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
       })
+      .remainingAccounts([
+        { pubkey: leaderPool, isWritable: false, isSigner: false },
+        { pubkey: aPool, isWritable: false, isSigner: false },
+        { pubkey: bPool, isWritable: false, isSigner: false },
+      ])
       .rpc();
 
     await program.methods
@@ -236,7 +317,13 @@ describe("settle_flight_claim", () => {
         flightPolicy: flightPolicyPda,
         leaderDepositToken: leaderDeposit,
         reinsurerPoolToken: reinsurerPool,
+        xolPoolToken: null,
+        xolEvent: null,
+        stopLossPoolToken: null,
+        beneficiaryToken: null,
+        currencyMint: mint,
         tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
       })
       .remainingAccounts([
         { pubkey: leaderPool, isWritable: true, isSigner: false },
@@ -7,10 +7,10 @@ import { useProtocolStore } from '@/store/useProtocolStore';
 import { useToast } from '@/components/common';
 import { useTranslation } from 'react-i18next';
 import { useProgram } from '@/hooks/useProgram';
-import { getMasterPolicyPDA } from '@/lib/pda';
+import { getMasterPolicyPDA, getProtocolConfigPDA } from '@/lib/pda';
 import { CURRENCY_MINT, DEFAULT_PAYOUT_TIERS } from '@/lib/constants';
 import { generateDemoKeypairs, setPoolWallet } from '@/lib/demo-keypairs';
-import { ConfirmRole } from '@/lib/idl/open_parametric';
+import { ConfirmRole, maxPolicyPayout, payoutTiersFromBands } from '@/lib/idl/open_parametric';
 
 export function MasterContractSetup() {
   const { mode, masterActive, processStep, shares, masterPolicyPDA, setTerms, onChainSetTerms, setMasterPolicyPDA, refreshPool } = useProtocolStore();
@@ -113,16 +113,24 @@ export function MasterContractSetup() {
       const [createPartBPoolIx, initPartBPoolIx] = makePoolIxs(partBPoolKp);
       const [createReinsurerPoolIx, initReinsurerPoolIx] = makePoolIxs(reinsurerPoolKp);
 
+      // UI 지연 구간 금액을 온체인 payout_tiers(구간 하한 분 + 지급액)와 결항 지급액으로 변환
+      const { payoutTiers, cancellationPayout } = payoutTiersFromBands({
+        delay2h: payout2h * 1_000_000,
+        delay3h: payout3h * 1_000_000,
+        delay4to5h: payout4to5h * 1_000_000,
+        delay6hOrCancelled: payout6h * 1_000_000,
+      });
+      const maxPolicyCount = 1_000;
+      const [protocolConfigPDA] = getProtocolConfigPDA();
+
       const createMasterIx = await prog.methods
         .createMasterPolicy({
           masterId: masterIdBN,
           coverageStartTs: new BN(Math.floor(new Date(coverageStart).getTime() / 1000)),
           coverageEndTs: new BN(Math.floor(new Date(coverageEnd).getTime() / 1000)),
           premiumPerPolicy: new BN(premium * 1_000_000),
-          payoutDelay2H: new BN(payout2h * 1_000_000),
-          payoutDelay3H: new BN(payout3h * 1_000_000),
-          payoutDelay4To5H: new BN(payout4to5h * 1_000_000),
-          payoutDelay6HOrCancelled: new BN(payout6h * 1_000_000),
+          payoutTiers: payoutTiers.map(t => ({ minDelayMinutes: t.minDelayMinutes, payout: new BN(t.payout) })),
+          cancellationPayout: new BN(cancellationPayout),
           cededRatioBps: 5000,
           reinsCommissionBps: 1000,
           // demo: 데모에서는 3명 모두 다른 지갑 키페어 사용
@@ -131,17 +139,41 @@ export function MasterContractSetup() {
             { insurer: partAKp.publicKey, shareBps: shares.partA * 100 },
             { insurer: partBKp.publicKey, shareBps: shares.partB * 100 },
           ],
+          // demo: 72h 후 무청구 만료, 이의 제기 기간 없음, XoL/stop-loss 미사용
+          resolutionTimeoutHours: 72,
+          expiryAction: 0,
+          oracleOnlyResolution: false,
+          resolvers: [],
+          resolverThreshold: 0,
+          challengePeriodSecs: new BN(0),
+          minLeadTimeSecs: new BN(0),
+          aggregateLiabilityLimit: new BN(maxPolicyPayout(payoutTiers, cancellationPayout)).mul(new BN(maxPolicyCount)),
+          maxPolicyCount: new BN(maxPolicyCount),
+          poolMarginBps: 0,
+          xolReinsurer: PublicKey.default,
+          xolPoolWallet: PublicKey.default,
+          xolRetention: new BN(0),
+          xolLimit: new BN(0),
+          stopLossReinsurer: PublicKey.default,
+          stopLossPoolWallet: PublicKey.default,
+          stopLossAttachmentBps: 0,
+          stopLossLimit: new BN(0),
+          commissionMode: 0,
+          deductionTiming: 0,
         })
         .accounts({
           leader: leaderKey,
           operator: operatorKey,
           reinsurer: reinsurerKey,
           currencyMint: CURRENCY_MINT,
+          protocolConfig: protocolConfigPDA,
           masterPolicy: masterPolicyPDA,
           leaderDepositWallet: leaderATA,
           reinsurerPoolWallet: reinsurerPoolKp.publicKey, // PDA-owned
           reinsurerDepositWallet: leaderATA,
-          tokenProgram: TOKEN_PROGRAM_ID,
+          feeTreasuryToken: null,
+          taxTreasuryToken: null,
+          systemProgram: SystemProgram.programId,
         })
         .instruction();
 
@@ -214,7 +246,10 @@ export function MasterContractSetup() {
 
       // 5건 최대 클레임(6h 티어) 감당 가능한 양으로 충전
       const NUM_CLAIMS = 5;
-      const maxPayoutRaw: number = masterData.payoutDelay6HOrCancelled.toNumber();
+      const maxPayoutRaw: number = maxPolicyPayout(
+        masterData.payoutTiers.map((t: { payout: BN }) => ({ payout: t.payout.toNumber() })),
+        masterData.cancellationPayout.toNumber(),
+      );
       const totalPayout = maxPayoutRaw * NUM_CLAIMS;
 
       const reinsurerEffBps: number = masterData.reinsurerEffectiveBps;
@@ -237,7 +272,7 @@ export function MasterContractSetup() {
       //   - insurer total: 55 USDC → 지분율대로 분배 (leader/partA/partB)
       // - 5건 여유분: reinsurer pool ~225 USDC, 각 insurer pool ~27.5×5 = 137.5 USDC 등
 
-      // 클릭 시 온체인 master 데이터에서 reinsurerEffectiveBps, payoutDelay6HOrCancelled, 각 참여사 shareBps, poolWallet
+      // 클릭 시 온체인 master 데이터에서 reinsurerEffectiveBps, payoutTiers/cancellationPayout 최대 지급액, 각 참여사 shareBps, poolWallet
       // 주소를 읽어옴
       // - 5건 × 최대 티어(100 USDC) = 500 USDC 기준으로 각 pool에 정확한 지분율대로 분배:
       //   - reinsurer pool → 45% = 225 USDC
@@ -48,7 +48,6 @@ export function ContractForm() {
       route,
       departureTs,
       payerToken: walletATA,
-      leaderDepositToken: walletATA,
     });
 
     if (!result.success) {
@@ -1,11 +1,11 @@
 import { useCallback, useState } from 'react';
-import { PublicKey } from '@solana/web3.js';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
 import BN from 'bn.js';
 import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
 import { useProgram } from './useProgram';
-import { getFlightPolicyPDA } from '@/lib/pda';
+import { getFlightPolicyPDA, getProtocolConfigPDA } from '@/lib/pda';
 import { sendTx, type TxResult } from '@/lib/tx';
-import type { CreateFlightPolicyParams } from '@/lib/idl/open_parametric';
+import type { CreateFlightPolicyParams, MasterPolicyAccount } from '@/lib/idl/open_parametric';
 
 export interface CreateFlightPolicyInput {
   masterPolicy: PublicKey;
@@ -15,9 +15,12 @@ export interface CreateFlightPolicyInput {
   route: string;
   departureTs: number; // unix seconds
   payerToken: PublicKey;
-  leaderDepositToken: PublicKey;
+  /** 지정 시 청구 정산 지급액을 이 토큰 계정으로 직접 보낸다(발급 시 mint 검증). */
+  beneficiaryToken?: PublicKey;
 }
 
+const optionalKey = (key: PublicKey): PublicKey | null => (key.equals(PublicKey.default) ? null : key);
+
 export function useCreateFlightPolicy() {
   const { program, provider, wallet } = useProgram();
   const [loading, setLoading] = useState(false);
@@ -32,6 +35,16 @@ export function useCreateFlightPolicy() {
       try {
         const childIdBN = new BN(input.childPolicyId);
         const [flightPolicyPDA] = getFlightPolicyPDA(input.masterPolicy, childIdBN);
+        const [protocolConfigPDA] = getProtocolConfigPDA();
+
+        // eslint-disable-next-line @typescript-eslint/no-explicit-any
+        const prog = program as any;
+        // 풀/treasury 지갑과 통화 mint는 마스터 계정에 기록된 값을 그대로 사용한다.
+        const master: MasterPolicyAccount & {
+          xolPoolWallet: PublicKey;
+          feeTreasuryToken: PublicKey;
+          taxTreasuryToken: PublicKey;
+        } = await prog.account.masterPolicy.fetch(input.masterPolicy);
 
         const params: CreateFlightPolicyParams = {
           childPolicyId: childIdBN,
@@ -39,21 +52,33 @@ export function useCreateFlightPolicy() {
           flightNo: input.flightNo,
           route: input.route,
           departureTs: new BN(input.departureTs),
+          delayFeedId: Array(32).fill(0),
+          cancelFeedId: Array(32).fill(0),
+          beneficiaryToken: input.beneficiaryToken ?? PublicKey.default,
         };
 
-        // eslint-disable-next-line @typescript-eslint/no-explicit-any
-        const prog = program as any;
         const result = await sendTx(provider, () =>
           prog.methods
             .createFlightPolicyFromMaster(params)
             .accounts({
               creator: wallet.publicKey,
+              protocolConfig: protocolConfigPDA,
               masterPolicy: input.masterPolicy,
               flightPolicy: flightPolicyPDA,
               payerToken: input.payerToken,
-              leaderDepositToken: input.leaderDepositToken,
+              leaderDepositToken: master.leaderDepositWallet,
+              reinsurerPoolToken: master.reinsurerPoolWallet,
+              xolPoolToken: optionalKey(master.xolPoolWallet),
+              feeTreasuryToken: optionalKey(master.feeTreasuryToken),
+              taxTreasuryToken: optionalKey(master.taxTreasuryToken),
+              beneficiaryToken: input.beneficiaryToken ?? null,
+              currencyMint: master.currencyMint,
               tokenProgram: TOKEN_PROGRAM_ID,
+              systemProgram: SystemProgram.programId,
             })
+            .remainingAccounts(
+              master.participants.map(p => ({ pubkey: p.poolWallet, isSigner: false, isWritable: false })),
+            )
             .rpc(),
         );
         return result;
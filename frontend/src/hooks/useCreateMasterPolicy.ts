@@ -1,31 +1,40 @@
 import { useCallback, useState } from 'react';
-import { PublicKey } from '@solana/web3.js';
+import { PublicKey, SystemProgram } from '@solana/web3.js';
 import BN from 'bn.js';
 import { useProgram } from './useProgram';
-import { getMasterPolicyPDA } from '@/lib/pda';
+import { getMasterPolicyPDA, getProtocolConfigPDA } from '@/lib/pda';
 import { sendTx, type TxResult } from '@/lib/tx';
-import type { CreateMasterPolicyParams, MasterParticipantInit } from '@/lib/idl/open_parametric';
+import {
+  maxPolicyPayout,
+  type CreateMasterPolicyParams,
+  type MasterParticipantInit,
+} from '@/lib/idl/open_parametric';
 
 export interface CreateMasterPolicyInput {
   masterId: number;
   coverageStartTs: number; // unix seconds
   coverageEndTs: number;
   premiumPerPolicy: number; // in token base units
-  payoutDelay2h: number;
-  payoutDelay3h: number;
-  payoutDelay4to5h: number;
-  payoutDelay6hOrCancelled: number;
+  payoutTiers: { minDelayMinutes: number; payout: number }[]; // payout in token base units
+  cancellationPayout: number;
   cededRatioBps: number;
   reinsCommissionBps: number;
+  maxPolicyCount?: number;
   operator: PublicKey;
   reinsurer: PublicKey;
   currencyMint: PublicKey;
   leaderDepositWallet: PublicKey;
   reinsurerPoolWallet: PublicKey;
   reinsurerDepositWallet: PublicKey;
+  /** 프로토콜 수수료/보험료 세금이 설정된 경우 config treasury 소유의 통화 mint 토큰 계정 */
+  feeTreasuryToken?: PublicKey;
+  taxTreasuryToken?: PublicKey;
   participants: { insurer: PublicKey; shareBps: number }[];
 }
 
+/** maxPolicyCount 미지정 시 발급 건수 한도 */
+const DEFAULT_MAX_POLICY_COUNT = 1_000;
+
 export function useCreateMasterPolicy() {
   const { program, provider, wallet } = useProgram();
   const [loading, setLoading] = useState(false);
@@ -41,16 +50,20 @@ export function useCreateMasterPolicy() {
         const masterIdBN = new BN(input.masterId);
         const leader = wallet.publicKey;
         const [masterPolicyPDA] = getMasterPolicyPDA(leader, masterIdBN);
+        const [protocolConfigPDA] = getProtocolConfigPDA();
+        const maxPolicyCount = input.maxPolicyCount ?? DEFAULT_MAX_POLICY_COUNT;
+        const maxPayout = maxPolicyPayout(input.payoutTiers, input.cancellationPayout);
 
         const params: CreateMasterPolicyParams = {
           masterId: masterIdBN,
           coverageStartTs: new BN(input.coverageStartTs),
           coverageEndTs: new BN(input.coverageEndTs),
           premiumPerPolicy: new BN(input.premiumPerPolicy),
-          payoutDelay2h: new BN(input.payoutDelay2h),
-          payoutDelay3h: new BN(input.payoutDelay3h),
-          payoutDelay4to5h: new BN(input.payoutDelay4to5h),
-          payoutDelay6hOrCancelled: new BN(input.payoutDelay6hOrCancelled),
+          payoutTiers: input.payoutTiers.map(t => ({
+            minDelayMinutes: t.minDelayMinutes,
+            payout: new BN(t.payout),
+          })),
+          cancellationPayout: new BN(input.cancellationPayout),
           cededRatioBps: input.cededRatioBps,
           reinsCommissionBps: input.reinsCommissionBps,
           participants: input.participants.map(
@@ -59,6 +72,27 @@ export function useCreateMasterPolicy() {
               shareBps: p.shareBps,
             }),
           ),
+          // Demo 기본 운영 조건: 72h 후 무청구 만료, 이의 제기 기간 없음, XoL/stop-loss 미사용
+          resolutionTimeoutHours: 72,
+          expiryAction: 0,
+          oracleOnlyResolution: false,
+          resolvers: [],
+          resolverThreshold: 0,
+          challengePeriodSecs: new BN(0),
+          minLeadTimeSecs: new BN(0),
+          aggregateLiabilityLimit: new BN(maxPayout).mul(new BN(maxPolicyCount)),
+          maxPolicyCount: new BN(maxPolicyCount),
+          poolMarginBps: 0,
+          xolReinsurer: PublicKey.default,
+          xolPoolWallet: PublicKey.default,
+          xolRetention: new BN(0),
+          xolLimit: new BN(0),
+          stopLossReinsurer: PublicKey.default,
+          stopLossPoolWallet: PublicKey.default,
+          stopLossAttachmentBps: 0,
+          stopLossLimit: new BN(0),
+          commissionMode: 0,
+          deductionTiming: 0,
         };
 
         // eslint-disable-next-line @typescript-eslint/no-explicit-any
@@ -71,10 +105,14 @@ export function useCreateMasterPolicy() {
               operator: input.operator,
               reinsurer: input.reinsurer,
               currencyMint: input.currencyMint,
+              protocolConfig: protocolConfigPDA,
               masterPolicy: masterPolicyPDA,
               leaderDepositWallet: input.leaderDepositWallet,
               reinsurerPoolWallet: input.reinsurerPoolWallet,
               reinsurerDepositWallet: input.reinsurerDepositWallet,
+              feeTreasuryToken: input.feeTreasuryToken ?? null,
+              taxTreasuryToken: input.taxTreasuryToken ?? null,
+              systemProgram: SystemProgram.programId,
             })
             .rpc(),
         );
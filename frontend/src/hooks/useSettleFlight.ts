@@ -1,6 +1,5 @@
 import { useCallback, useState } from 'react';
-import { PublicKey } from '@solana/web3.js';
-import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
 import { useProgram } from './useProgram';
 import { sendTx, type TxResult } from '@/lib/tx';
 import type { MasterPolicyAccount } from '@/lib/idl/open_parametric';
@@ -12,6 +11,10 @@ export interface SettleFlightClaimInput {
   reinsurerPoolToken: PublicKey;
   /** Pool wallet accounts for each participant (same order as master.participants) */
   participantPoolWallets: PublicKey[];
+  /** Master currency mint (master.currencyMint) */
+  currencyMint: PublicKey;
+  /** XoL event PDA (getXolEventPDA) — required when the master has an XoL layer */
+  xolEvent?: PublicKey;
 }
 
 export interface SettleFlightNoClaimInput {
@@ -21,8 +24,25 @@ export interface SettleFlightNoClaimInput {
   reinsurerDepositToken: PublicKey;
   /** Deposit wallet accounts for each participant (same order as master.participants) */
   participantDepositWallets: PublicKey[];
+  /** Master currency mint (master.currencyMint) */
+  currencyMint: PublicKey;
+  /** Protocol fee treasury token account — required when fees are deducted at settlement */
+  feeTreasuryToken?: PublicKey;
+  /** Premium tax treasury token account — required when tax is deducted at settlement */
+  taxTreasuryToken?: PublicKey;
 }
 
+/** Token program that owns the mint (SPL Token or Token-2022) */
+async function getMintTokenProgram(connection: Connection, mint: PublicKey): Promise<PublicKey> {
+  const info = await connection.getAccountInfo(mint);
+  if (!info) throw new Error(`Mint account not found: ${mint.toBase58()}`);
+  return info.owner;
+}
+
+/** Treasury keys are unset (default pubkey) when the master has no treasury configured */
+const optionalKey = (key: PublicKey): PublicKey | undefined =>
+  key.equals(PublicKey.default) ? undefined : key;
+
 export function useSettleFlight() {
   const { program, provider, wallet } = useProgram();
   const [loading, setLoading] = useState(false);
@@ -37,6 +57,7 @@ export function useSettleFlight() {
       try {
         // eslint-disable-next-line @typescript-eslint/no-explicit-any
         const prog = program as any;
+        const tokenProgram = await getMintTokenProgram(provider.connection, input.currencyMint);
         const result = await sendTx(provider, () =>
           prog.methods
             .settleFlightClaim()
@@ -46,7 +67,13 @@ export function useSettleFlight() {
               flightPolicy: input.flightPolicy,
               leaderDepositToken: input.leaderDepositToken,
               reinsurerPoolToken: input.reinsurerPoolToken,
-              tokenProgram: TOKEN_PROGRAM_ID,
+              xolPoolToken: null,
+              xolEvent: input.xolEvent ?? null,
+              stopLossPoolToken: null,
+              beneficiaryToken: null,
+              currencyMint: input.currencyMint,
+              tokenProgram,
+              systemProgram: SystemProgram.programId,
             })
             .remainingAccounts(
               input.participantPoolWallets.map((pk) => ({
@@ -78,6 +105,7 @@ export function useSettleFlight() {
       try {
         // eslint-disable-next-line @typescript-eslint/no-explicit-any
         const prog = program as any;
+        const tokenProgram = await getMintTokenProgram(provider.connection, input.currencyMint);
         const result = await sendTx(provider, () =>
           prog.methods
             .settleFlightNoClaim()
@@ -87,7 +115,10 @@ export function useSettleFlight() {
               flightPolicy: input.flightPolicy,
               leaderDepositToken: input.leaderDepositToken,
               reinsurerDepositToken: input.reinsurerDepositToken,
-              tokenProgram: TOKEN_PROGRAM_ID,
+              feeTreasuryToken: input.feeTreasuryToken ?? null,
+              taxTreasuryToken: input.taxTreasuryToken ?? null,
+              currencyMint: input.currencyMint,
+              tokenProgram,
             })
             .remainingAccounts(
               input.participantDepositWallets.map((pk) => ({
@@ -116,6 +147,9 @@ export function useSettleFlight() {
     leaderDepositWallet: master.leaderDepositWallet,
     reinsurerPoolWallet: master.reinsurerPoolWallet,
     reinsurerDepositWallet: master.reinsurerDepositWallet,
+    currencyMint: master.currencyMint,
+    feeTreasuryToken: optionalKey(master.feeTreasuryToken),
+    taxTreasuryToken: optionalKey(master.taxTreasuryToken),
   });
 
   return { settleFlightClaim, settleFlightNoClaim, buildSettleAccounts, loading };
@@ -34,6 +34,8 @@ export const FLIGHT_POLICY_STATES = [
   'Paid',
   'NoClaim',
   'Expired',
+  'Voided',
+  'Disputed',
 ] as const;
 
 export const UNDERWRITING_STATUSES = ['Proposed', 'Open', 'Finalized', 'Failed'] as const;
@@ -22,6 +22,8 @@ export enum FlightPolicyStatus {
   Paid = 3,
   NoClaim = 4,
   Expired = 5,
+  Voided = 6,
+  Disputed = 7,
 }
 
 export enum ConfirmRole {
@@ -42,6 +44,11 @@ export enum PolicyState {
 
 /* ── On-chain Account Types ── */
 
+export interface PayoutTier {
+  minDelayMinutes: number;
+  payout: BN;
+}
+
 export interface MasterParticipant {
   insurer: PublicKey;
   shareBps: number;
@@ -58,10 +65,8 @@ export interface MasterPolicyAccount {
   coverageStartTs: BN;
   coverageEndTs: BN;
   premiumPerPolicy: BN;
-  payoutDelay2h: BN;
-  payoutDelay3h: BN;
-  payoutDelay4to5h: BN;
-  payoutDelay6hOrCancelled: BN;
+  payoutTiers: PayoutTier[];
+  cancellationPayout: BN;
   cededRatioBps: number;
   reinsCommissionBps: number;
   reinsurerEffectiveBps: number;
@@ -74,6 +79,8 @@ export interface MasterPolicyAccount {
   status: number;
   createdAt: BN;
   bump: number;
+  feeTreasuryToken: PublicKey;
+  taxTreasuryToken: PublicKey;
 }
 
 export interface FlightPolicyAccount {
@@ -107,13 +114,31 @@ export interface CreateMasterPolicyParams {
   coverageStartTs: BN;
   coverageEndTs: BN;
   premiumPerPolicy: BN;
-  payoutDelay2h: BN;
-  payoutDelay3h: BN;
-  payoutDelay4to5h: BN;
-  payoutDelay6hOrCancelled: BN;
+  payoutTiers: PayoutTier[];
+  cancellationPayout: BN;
   cededRatioBps: number;
   reinsCommissionBps: number;
   participants: MasterParticipantInit[];
+  resolutionTimeoutHours: number;
+  expiryAction: number;
+  oracleOnlyResolution: boolean;
+  resolvers: PublicKey[];
+  resolverThreshold: number;
+  challengePeriodSecs: BN;
+  minLeadTimeSecs: BN;
+  aggregateLiabilityLimit: BN;
+  maxPolicyCount: BN;
+  poolMarginBps: number;
+  xolReinsurer: PublicKey;
+  xolPoolWallet: PublicKey;
+  xolRetention: BN;
+  xolLimit: BN;
+  stopLossReinsurer: PublicKey;
+  stopLossPoolWallet: PublicKey;
+  stopLossAttachmentBps: number;
+  stopLossLimit: BN;
+  commissionMode: number;
+  deductionTiming: number;
 }
 
 export interface CreateFlightPolicyParams {
@@ -122,6 +147,10 @@ export interface CreateFlightPolicyParams {
   flightNo: string;
   route: string;
   departureTs: BN;
+  delayFeedId: number[];
+  cancelFeedId: number[];
+  /** PublicKey.default면 리더 deposit으로 수취만 하고 직접 지급하지 않는다. */
+  beneficiaryToken: PublicKey;
 }
 
 /* ── Status label helpers ── */
@@ -141,4 +170,55 @@ export const FLIGHT_STATUS_LABELS: Record<number, string> = {
   [FlightPolicyStatus.Paid]: 'Paid',
   [FlightPolicyStatus.NoClaim]: 'NoClaim',
   [FlightPolicyStatus.Expired]: 'Expired',
+  [FlightPolicyStatus.Voided]: 'Voided',
+  [FlightPolicyStatus.Disputed]: 'Disputed',
 };
+
+/* ── Payout tier helpers ── */
+
+/** Demo UI 지연 구간(2h/3h/4-5h/6h+)의 하한(분). 6h+ 구간 금액은 결항 지급액으로도 쓴다. */
+export const DEMO_TIER_MIN_DELAYS = {
+  delay2h: 120,
+  delay3h: 180,
+  delay4to5h: 240,
+  delay6hOrCancelled: 360,
+} as const;
+
+export type DemoPayoutBands = Record<keyof typeof DEMO_TIER_MIN_DELAYS, number>;
+
+/** UI 구간 금액(base unit) → 온체인 payout_tiers / cancellation_payout */
+export function payoutTiersFromBands(bands: DemoPayoutBands): {
+  payoutTiers: { minDelayMinutes: number; payout: number }[];
+  cancellationPayout: number;
+} {
+  const keys = Object.keys(DEMO_TIER_MIN_DELAYS) as (keyof DemoPayoutBands)[];
+  return {
+    payoutTiers: keys.map(k => ({ minDelayMinutes: DEMO_TIER_MIN_DELAYS[k], payout: bands[k] })),
+    cancellationPayout: bands.delay6hOrCancelled,
+  };
+}
+
+/** 온체인 payout_tiers → UI 구간 금액. 구간 하한 이하의 가장 높은 티어 금액을 쓴다. */
+export function bandsFromPayoutTiers(
+  tiers: { minDelayMinutes: number; payout: number }[],
+  cancellationPayout: number,
+): DemoPayoutBands {
+  const payoutAt = (delay: number) =>
+    tiers
+      .filter(t => t.minDelayMinutes <= delay)
+      .reduce((best, t) => (t.minDelayMinutes >= best.minDelayMinutes ? t : best), {
+        minDelayMinutes: 0,
+        payout: 0,
+      }).payout;
+  return {
+    delay2h: payoutAt(DEMO_TIER_MIN_DELAYS.delay2h),
+    delay3h: payoutAt(DEMO_TIER_MIN_DELAYS.delay3h),
+    delay4to5h: payoutAt(DEMO_TIER_MIN_DELAYS.delay4to5h),
+    delay6hOrCancelled: Math.max(payoutAt(DEMO_TIER_MIN_DELAYS.delay6hOrCancelled), cancellationPayout),
+  };
+}
+
+/** 한 건이 받을 수 있는 최대 지급액(티어 최댓값과 결항 지급액 중 큰 값). */
+export function maxPolicyPayout(tiers: { payout: number }[], cancellationPayout: number): number {
+  return tiers.reduce((max, t) => Math.max(max, t.payout), cancellationPayout);
+}
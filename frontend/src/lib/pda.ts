@@ -40,6 +40,35 @@ export function getFlightPolicyPDA(
   );
 }
 
+/**
+ * Derive PDA for the per-flight XoL event loss account.
+ * Seeds: ["xol_event", master_policy, flight_no, departure_ts_le]
+ */
+export function getXolEventPDA(
+  masterPolicy: PublicKey,
+  flightNo: string,
+  departureTs: BN,
+  programId: PublicKey = PROGRAM_ID,
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from('xol_event'),
+      masterPolicy.toBuffer(),
+      Buffer.from(flightNo),
+      departureTs.toArrayLike(Buffer, 'le', 8),
+    ],
+    programId,
+  );
+}
+
+/**
+ * Derive PDA for the singleton ProtocolConfig account.
+ * Seeds: ["protocol_config"]
+ */
+export function getProtocolConfigPDA(programId: PublicKey = PROGRAM_ID): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync([Buffer.from('protocol_config')], programId);
+}
+
 /* ── Legacy Flow PDAs (for future use) ── */
 
 export function getPolicyPDA(
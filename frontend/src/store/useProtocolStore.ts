@@ -1,6 +1,6 @@
 import { create } from 'zustand';
 import { persist } from 'zustand/middleware';
-import { MasterPolicyStatus, FlightPolicyStatus, type MasterPolicyAccount } from '@/lib/idl/open_parametric';
+import { MasterPolicyStatus, FlightPolicyStatus, bandsFromPayoutTiers, type MasterPolicyAccount } from '@/lib/idl/open_parametric';
 import type { FlightPolicyWithKey } from '@/hooks/useFlightPolicies';
 import i18n from '@/i18n';
 
@@ -687,13 +687,14 @@ export const useProtocolStore = create<ProtocolState>()(persist((set, get) => ({
       cededRatioBps: data.cededRatioBps,
       reinsCommissionBps: data.reinsCommissionBps,
       ...(data.premiumPerPolicy && { premiumPerPolicy: data.premiumPerPolicy.toNumber() / 1_000_000 }),
-      ...(data.payoutDelay2h && {
-        payoutTiers: {
-          delay2h: data.payoutDelay2h.toNumber() / 1_000_000,
-          delay3h: data.payoutDelay3h.toNumber() / 1_000_000,
-          delay4to5h: data.payoutDelay4to5h.toNumber() / 1_000_000,
-          delay6hOrCancelled: data.payoutDelay6hOrCancelled.toNumber() / 1_000_000,
-        },
+      ...(data.payoutTiers && {
+        payoutTiers: bandsFromPayoutTiers(
+          data.payoutTiers.map(t => ({
+            minDelayMinutes: t.minDelayMinutes,
+            payout: t.payout.toNumber() / 1_000_000,
+          })),
+          data.cancellationPayout.toNumber() / 1_000_000,
+        ),
       }),
       policyStateIdx: isActive ? 3 : processStep > 0 ? 0 : -1,
     });